@@ -0,0 +1,291 @@
+## Words used across screens
+
+common-on = On
+common-off = Off
+common-add = Add
+common-set = Set
+common-save = Save
+common-clear = Clear
+common-reset = Reset
+common-close = Close
+common-cancel = Cancel
+common-remove = Remove
+common-unknown = Unknown
+
+## Pages and the side bar
+
+page-home = Home
+page-home-title = Home Page
+page-shows = Shows
+page-movies = Movies
+page-comments = Comments
+page-settings = Settings
+page-search = Search
+page-custom = Custom
+page-new-collection = New collection
+page-play-queue = Play queue ({ $count })
+search-placeholder = Search
+
+## Sorting and filtering
+
+sort-by = Sort by:
+sort-name = Name
+sort-duration = Duration
+sort-progress = Progress
+sort-rating = Rating
+sort-release = Release
+sort-comments = Comments
+sort-added = Date Added
+sort-recent = Recent
+
+filter-title = Filters
+filter-any = Any
+filter-and = AND
+filter-or = OR
+filter-progress = Progress:
+filter-rating = Rating:
+filter-comments = Comments:
+filter-release = Release:
+filter-duration = Duration:
+filter-hours = hrs
+filter-minutes = mins
+filter-mode = Combination mode:
+filter-clear = Clear filters
+
+## Videos
+
+duration-hours =
+    { $hours ->
+        [one] { $hours } hour
+       *[other] { $hours } hours
+    }
+duration-minutes =
+    { $minutes ->
+        [one] { $minutes } min
+       *[other] { $minutes } mins
+    }
+
+view-overview = Overview
+view-comments = Comments
+view-data = Data
+view-collections = Collections
+
+details-duration = Duration
+details-rating = Rating
+details-comments = Comments
+details-release = Release Date
+details-added = Date Added
+details-watch-count = Watch Count
+details-watch-progress = Watch Progress
+details-recent = Recent Watch
+details-loudness = Loudness
+details-true-peak = True Peak
+details-bookmarks = Bookmarks
+
+preview-play = Play
+preview-play-next = Play next
+preview-add-to-queue = Add to queue
+preview-play-season = Play season
+
+## Settings
+
+language = Language
+
+settings-playback = Playback
+settings-seek = Seek
+settings-long-seek = Seek with shift
+settings-speed-step = Speed step
+settings-volume-step = Volume step
+settings-default-volume = Default volume
+settings-completion = Watched after
+
+settings-library = Library
+settings-no-folders = No folders added
+settings-add-folder = Add folder
+settings-recursive = Look in subfolders
+settings-hidden = Include hidden files
+
+settings-appearance = Appearance
+settings-theme = Theme
+settings-themes-folder = Custom palettes are read from { $folder }
+settings-ui-scale = Interface size
+settings-text-scale = Text size
+settings-layout = Library layout
+settings-grid = Grid
+settings-list = List
+
+settings-subtitles = Subtitles
+settings-subtitles-show = Show
+settings-subtitles-size = Size
+settings-subtitles-position = Distance from edge
+settings-subtitles-background = Background
+
+settings-keybindings = Keybindings
+settings-reset-keys = Reset to defaults
+settings-keys-player = Player
+settings-keys-library = Library
+settings-key-conflict = { $chord } is bound to { $actions }
+settings-press-key = Press a key...
+settings-add-key = Add key
+settings-add-click = Add click
+
+theme-system = Follow system
+theme-light = Light
+theme-dark = Dark
+
+subtitles-always = Always
+subtitles-foreign = Foreign audio
+subtitles-forced-only = Forced only
+subtitles-off = Off
+
+## Actions keys can be bound to
+
+action-toggle-play = Play or pause
+action-toggle-fullscreen = Toggle fullscreen
+action-exit-fullscreen = Exit fullscreen
+action-seek-back = Seek back
+action-seek-back-far = Seek back further
+action-seek-forward = Seek forward
+action-seek-forward-far = Seek forward further
+action-volume-up = Volume up
+action-volume-down = Volume down
+action-toggle-mute = Mute
+action-speed-up = Speed up
+action-slow-down = Slow down
+action-reset-speed = Reset speed
+action-step-forward = Next frame
+action-step-backward = Previous frame
+action-previous-chapter = Previous chapter
+action-next-chapter = Next chapter
+action-play-previous = Previous video
+action-play-next = Next video
+action-toggle-queue = Show queue
+action-toggle-subtitles = Toggle subtitles
+action-subtitles-earlier = Subtitles earlier
+action-subtitles-later = Subtitles later
+action-audio-earlier = Audio earlier
+action-audio-later = Audio later
+action-cycle-loop = Set or clear loop
+action-loop-start-earlier = Loop start earlier
+action-loop-start-later = Loop start later
+action-loop-end-earlier = Loop end earlier
+action-loop-end-later = Loop end later
+action-screenshot = Screenshot
+action-add-bookmark = Add bookmark
+action-set-clip-in = Set clip start
+action-set-clip-out = Set clip end
+action-mark-credits = Mark credits
+action-show-config = Show adjustments
+action-leave-player = Leave player
+action-back = Go back
+action-forward = Go forward
+action-play-queue = Play queue
+action-toggle-view = Switch between grid and list
+action-randomize = Pick a random video
+action-home = Go home
+action-settings = Open settings
+
+## Player
+
+player-frame = Frame { $frame } · { $time }
+player-volume = Volume: { $volume }%
+player-zoom = Zoom: { $zoom }%
+player-auto-crop-on = Auto crop on
+player-auto-crop-off = Auto crop off
+player-subtitle-delay = Subtitle delay: { $delay } ms
+player-audio-delay = Audio delay: { $delay } ms
+player-loop = Loop: { $start } – { $end }
+player-loop-cleared = Loop cleared
+player-bookmark = Bookmark { $number }
+player-clip-in = In: { $time }
+player-clip-out = Out: { $time }
+player-adjustments-saved-all = Adjustments saved for all videos
+player-adjustments-saved-video = Adjustments saved for this video
+player-exported = Exported to { $path }
+player-export-failed = Export failed: { $error }
+player-export-cancelled = Export cancelled
+player-no-frame = There is no frame to capture yet
+player-screenshot-saved = Screenshot saved to { $path }
+player-screenshot-failed = Could not save screenshot: { $error }
+player-poster-updated = Poster updated
+player-open-failed = Could not open { $name }: { $error }
+player-audio-filters-failed = Sound filters are off: { $error }
+player-subtitle-files = Subtitles
+
+player-chapter = Chapter { $number }
+player-track = Track { $number }
+
+menu-audio = Audio
+menu-subtitles = Subtitles
+menu-secondary-subtitles = Secondary subtitles
+menu-load-file = Load file…
+menu-add-bookmark = Add bookmark
+menu-screenshot = Take screenshot
+menu-export = Export clip…
+
+loop-title = A–B loop
+bookmarks-title = Bookmarks
+bookmarks-name = Name
+bookmarks-rename = Rename
+
+adjust-title = Video adjustments
+adjust-brightness = Brightness
+adjust-contrast = Contrast
+adjust-saturation = Saturation
+adjust-hue = Hue
+adjust-gamma = Gamma
+adjust-save-video = Save for video
+adjust-save-all = Save for all
+
+audio-title = Audio
+audio-night-mode = Night mode
+audio-normalize = Normalize loudness
+audio-equalizer = Equalizer
+audio-delay = Audio delay { $delay } ms
+
+equalizer-flat = Flat
+equalizer-bass = Bass boost
+equalizer-treble = Treble boost
+equalizer-voice = Voice
+equalizer-cinema = Cinema
+equalizer-custom = Custom
+
+framing-title = Framing
+framing-aspect = Aspect
+framing-size = Size
+framing-custom-aspect = Custom, e.g. 21:9
+framing-zoom = Zoom { $zoom }%
+framing-reset-zoom = Reset zoom
+framing-crop-left = Crop left
+framing-crop-top = Crop top
+framing-crop-right = Crop right
+framing-crop-bottom = Crop bottom
+framing-crop-black-bars = Crop black bars
+framing-reset-crop = Reset crop
+
+aspect-auto = Auto
+
+fit-contain = Fit
+fit-cover = Fill
+fit-stretch = Stretch
+fit-original = Original size
+
+export-title = Export clip
+export-range = { $start } – { $end } ({ $length } s)
+export-in = In
+export-out = Out
+export-width = Width
+export-fps = FPS
+export-start = Export…
+export-poster = Use as poster
+export-original = Original streams
+export-gif = Animated GIF
+export-webp = Animated WebP
+
+queue-title = Queue ({ $count })
+queue-up-next = Up next in { $seconds }
+queue-play-now = Play now
+
+repeat-off = Repeat off
+repeat-one = Repeat one
+repeat-all = Repeat all
@@ -0,0 +1,287 @@
+## Mots utilisés sur plusieurs écrans
+
+common-on = Activé
+common-off = Désactivé
+common-add = Ajouter
+common-set = Définir
+common-save = Enregistrer
+common-clear = Effacer
+common-reset = Réinitialiser
+common-close = Fermer
+common-cancel = Annuler
+common-remove = Retirer
+common-unknown = Inconnu
+
+## Pages et barre latérale
+
+page-home = Accueil
+page-home-title = Page d’accueil
+page-shows = Séries
+page-movies = Films
+page-comments = Commentaires
+page-settings = Paramètres
+page-search = Recherche
+page-custom = Personnalisé
+page-new-collection = Nouvelle collection
+page-play-queue = File de lecture ({ $count })
+search-placeholder = Rechercher
+
+## Tri et filtres
+
+sort-by = Trier par :
+sort-name = Nom
+sort-duration = Durée
+sort-progress = Progression
+sort-rating = Note
+sort-release = Sortie
+sort-comments = Commentaires
+sort-added = Date d’ajout
+sort-recent = Récent
+
+filter-title = Filtres
+filter-any = Tous
+filter-and = ET
+filter-or = OU
+filter-progress = Progression :
+filter-rating = Note :
+filter-comments = Commentaires :
+filter-release = Sortie :
+filter-duration = Durée :
+filter-hours = h
+filter-minutes = min
+filter-mode = Combinaison :
+filter-clear = Effacer les filtres
+
+## Vidéos
+
+duration-hours =
+    { $hours ->
+        [one] { $hours } heure
+       *[other] { $hours } heures
+    }
+duration-minutes = { $minutes } min
+
+view-overview = Aperçu
+view-comments = Commentaires
+view-data = Données
+view-collections = Collections
+
+details-duration = Durée
+details-rating = Note
+details-comments = Commentaires
+details-release = Date de sortie
+details-added = Date d’ajout
+details-watch-count = Visionnages
+details-watch-progress = Progression
+details-recent = Dernier visionnage
+details-loudness = Sonie
+details-true-peak = Crête réelle
+details-bookmarks = Signets
+
+preview-play = Lire
+preview-play-next = Lire ensuite
+preview-add-to-queue = Ajouter à la file
+preview-play-season = Lire la saison
+
+## Paramètres
+
+language = Langue
+
+settings-playback = Lecture
+settings-seek = Saut
+settings-long-seek = Saut avec Maj
+settings-speed-step = Pas de vitesse
+settings-volume-step = Pas de volume
+settings-default-volume = Volume par défaut
+settings-completion = Vu après
+
+settings-library = Bibliothèque
+settings-no-folders = Aucun dossier ajouté
+settings-add-folder = Ajouter un dossier
+settings-recursive = Parcourir les sous-dossiers
+settings-hidden = Inclure les fichiers cachés
+
+settings-appearance = Apparence
+settings-theme = Thème
+settings-themes-folder = Les palettes personnalisées sont lues depuis { $folder }
+settings-ui-scale = Taille de l’interface
+settings-text-scale = Taille du texte
+settings-layout = Disposition de la bibliothèque
+settings-grid = Grille
+settings-list = Liste
+
+settings-subtitles = Sous-titres
+settings-subtitles-show = Afficher
+settings-subtitles-size = Taille
+settings-subtitles-position = Distance du bord
+settings-subtitles-background = Fond
+
+settings-keybindings = Raccourcis
+settings-reset-keys = Rétablir les valeurs par défaut
+settings-keys-player = Lecteur
+settings-keys-library = Bibliothèque
+settings-key-conflict = { $chord } est associé à { $actions }
+settings-press-key = Appuyez sur une touche...
+settings-add-key = Ajouter une touche
+settings-add-click = Ajouter un clic
+
+theme-system = Suivre le système
+theme-light = Clair
+theme-dark = Sombre
+
+subtitles-always = Toujours
+subtitles-foreign = Audio étranger
+subtitles-forced-only = Forcés uniquement
+subtitles-off = Jamais
+
+## Actions associables à des touches
+
+action-toggle-play = Lecture ou pause
+action-toggle-fullscreen = Basculer le plein écran
+action-exit-fullscreen = Quitter le plein écran
+action-seek-back = Reculer
+action-seek-back-far = Reculer davantage
+action-seek-forward = Avancer
+action-seek-forward-far = Avancer davantage
+action-volume-up = Augmenter le volume
+action-volume-down = Baisser le volume
+action-toggle-mute = Couper le son
+action-speed-up = Accélérer
+action-slow-down = Ralentir
+action-reset-speed = Vitesse normale
+action-step-forward = Image suivante
+action-step-backward = Image précédente
+action-previous-chapter = Chapitre précédent
+action-next-chapter = Chapitre suivant
+action-play-previous = Vidéo précédente
+action-play-next = Vidéo suivante
+action-toggle-queue = Afficher la file
+action-toggle-subtitles = Afficher les sous-titres
+action-subtitles-earlier = Sous-titres plus tôt
+action-subtitles-later = Sous-titres plus tard
+action-audio-earlier = Audio plus tôt
+action-audio-later = Audio plus tard
+action-cycle-loop = Définir ou effacer la boucle
+action-loop-start-earlier = Début de boucle plus tôt
+action-loop-start-later = Début de boucle plus tard
+action-loop-end-earlier = Fin de boucle plus tôt
+action-loop-end-later = Fin de boucle plus tard
+action-screenshot = Capture d’écran
+action-add-bookmark = Ajouter un signet
+action-set-clip-in = Début de l’extrait
+action-set-clip-out = Fin de l’extrait
+action-mark-credits = Marquer le générique
+action-show-config = Afficher les réglages
+action-leave-player = Quitter le lecteur
+action-back = Retour
+action-forward = Suivant
+action-play-queue = Lire la file
+action-toggle-view = Basculer entre grille et liste
+action-randomize = Choisir une vidéo au hasard
+action-home = Aller à l’accueil
+action-settings = Ouvrir les paramètres
+
+## Lecteur
+
+player-frame = Image { $frame } · { $time }
+player-volume = Volume : { $volume } %
+player-zoom = Zoom : { $zoom } %
+player-auto-crop-on = Recadrage auto activé
+player-auto-crop-off = Recadrage auto désactivé
+player-subtitle-delay = Décalage des sous-titres : { $delay } ms
+player-audio-delay = Décalage audio : { $delay } ms
+player-loop = Boucle : { $start } – { $end }
+player-loop-cleared = Boucle effacée
+player-bookmark = Signet { $number }
+player-clip-in = Début : { $time }
+player-clip-out = Fin : { $time }
+player-adjustments-saved-all = Réglages enregistrés pour toutes les vidéos
+player-adjustments-saved-video = Réglages enregistrés pour cette vidéo
+player-exported = Exporté vers { $path }
+player-export-failed = Échec de l’export : { $error }
+player-export-cancelled = Export annulé
+player-no-frame = Aucune image à capturer pour l’instant
+player-screenshot-saved = Capture enregistrée dans { $path }
+player-screenshot-failed = Impossible d’enregistrer la capture : { $error }
+player-poster-updated = Affiche mise à jour
+player-open-failed = Impossible d’ouvrir { $name } : { $error }
+player-audio-filters-failed = Filtres audio désactivés : { $error }
+player-subtitle-files = Sous-titres
+
+player-chapter = Chapitre { $number }
+player-track = Piste { $number }
+
+menu-audio = Audio
+menu-subtitles = Sous-titres
+menu-secondary-subtitles = Sous-titres secondaires
+menu-load-file = Charger un fichier…
+menu-add-bookmark = Ajouter un signet
+menu-screenshot = Prendre une capture
+menu-export = Exporter un extrait…
+
+loop-title = Boucle A–B
+bookmarks-title = Signets
+bookmarks-name = Nom
+bookmarks-rename = Renommer
+
+adjust-title = Réglages vidéo
+adjust-brightness = Luminosité
+adjust-contrast = Contraste
+adjust-saturation = Saturation
+adjust-hue = Teinte
+adjust-gamma = Gamma
+adjust-save-video = Pour cette vidéo
+adjust-save-all = Pour toutes
+
+audio-title = Audio
+audio-night-mode = Mode nuit
+audio-normalize = Normaliser le volume
+audio-equalizer = Égaliseur
+audio-delay = Décalage audio { $delay } ms
+
+equalizer-flat = Neutre
+equalizer-bass = Basses renforcées
+equalizer-treble = Aigus renforcés
+equalizer-voice = Voix
+equalizer-cinema = Cinéma
+equalizer-custom = Personnalisé
+
+framing-title = Cadrage
+framing-aspect = Format
+framing-size = Taille
+framing-custom-aspect = Autre, ex. 21:9
+framing-zoom = Zoom { $zoom } %
+framing-reset-zoom = Réinitialiser le zoom
+framing-crop-left = Rogner à gauche
+framing-crop-top = Rogner en haut
+framing-crop-right = Rogner à droite
+framing-crop-bottom = Rogner en bas
+framing-crop-black-bars = Rogner les bandes noires
+framing-reset-crop = Réinitialiser le rognage
+
+aspect-auto = Auto
+
+fit-contain = Ajuster
+fit-cover = Remplir
+fit-stretch = Étirer
+fit-original = Taille d’origine
+
+export-title = Exporter un extrait
+export-range = { $start } – { $end } ({ $length } s)
+export-in = Début
+export-out = Fin
+export-width = Largeur
+export-fps = IPS
+export-start = Exporter…
+export-poster = Utiliser comme affiche
+export-original = Flux d’origine
+export-gif = GIF animé
+export-webp = WebP animé
+
+queue-title = File ({ $count })
+queue-up-next = À suivre dans { $seconds }
+queue-play-now = Lire maintenant
+
+repeat-off = Sans répétition
+repeat-one = Répéter une vidéo
+repeat-all = Tout répéter
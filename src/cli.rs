@@ -0,0 +1,162 @@
+//! Arguments given on the command line, for opening a video or a page of the
+//! library directly:
+//!
+//! ```text
+//! kino movie.mkv
+//! kino --fullscreen --start 1:23:00 --speed 1.25 movie.mkv
+//! kino https://example.com/trailer.webm
+//! kino --page movies --sort rating
+//! ```
+use clap::Parser;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+use url::Url;
+
+use crate::home::PageKind;
+use crate::utils::SortKind;
+
+/// The speeds playback can be started at.
+const SPEED: RangeInclusive<f64> = 0.25..=4.0;
+
+#[derive(Debug, Clone, Default, Parser)]
+#[command(version, about = "Plays videos and keeps track of your library")]
+pub struct Args {
+    /// A video file or URL to play
+    #[arg(value_name = "VIDEO", value_parser = Source::parse)]
+    pub source: Option<Source>,
+    /// Plays the video fullscreen
+    #[arg(long, requires = "source")]
+    pub fullscreen: bool,
+    /// Where to start playing, such as 1:23:00, 23:00 or 90
+    #[arg(long, value_name = "TIME", value_parser = timestamp, requires = "source")]
+    pub start: Option<Duration>,
+    /// How fast to play, from 0.25 to 4
+    #[arg(long, value_parser = speed, requires = "source")]
+    pub speed: Option<f64>,
+    /// The page of the library to open, movies or settings
+    #[arg(long, conflicts_with = "source")]
+    pub page: Option<PageKind>,
+    /// What the library is sorted by, such as rating or added
+    #[arg(long, conflicts_with = "source")]
+    pub sort: Option<SortKind>,
+}
+
+/// Where a video given on the command line is played from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Url(Url),
+}
+
+impl Source {
+    fn parse(input: &str) -> Result<Self, String> {
+        // Windows paths such as `C:\movie.mkv` read as a URL with a one
+        // letter scheme.
+        if let Some(url) = Url::parse(input).ok().filter(|url| url.scheme().len() > 1) {
+            if url.scheme() != "file" {
+                return Ok(Self::Url(url));
+            }
+
+            let path = url
+                .to_file_path()
+                .map_err(|_| format!("\"{input}\" is not a valid file URL"))?;
+            return Self::file(path);
+        }
+
+        Self::file(PathBuf::from(input))
+    }
+
+    fn file(path: PathBuf) -> Result<Self, String> {
+        let path = path
+            .canonicalize()
+            .map_err(|err| format!("Could not open \"{}\": {err}", path.display()))?;
+
+        if !path.is_file() {
+            return Err(format!("\"{}\" is not a file", path.display()));
+        }
+
+        Ok(Self::File(path))
+    }
+}
+
+/// Reads a position written as `h:mm:ss`, `m:ss` or seconds, which may have
+/// a fraction.
+fn timestamp(input: &str) -> Result<Duration, String> {
+    let invalid = || format!("\"{input}\" is not a time such as 1:23:00, 23:00 or 90");
+
+    let parts = input.trim().split(':').collect::<Vec<_>>();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+
+    let mut seconds = 0.0;
+    for part in parts {
+        let value = part
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite() && *value >= 0.0)
+            .ok_or_else(invalid)?;
+
+        seconds = seconds * 60.0 + value;
+    }
+
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+fn speed(input: &str) -> Result<f64, String> {
+    let speed = input
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("\"{input}\" is not a number"))?;
+
+    if !SPEED.contains(&speed) {
+        return Err(format!(
+            "The speed has to be between {} and {}",
+            SPEED.start(),
+            SPEED.end()
+        ));
+    }
+
+    Ok(speed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_reads_hours_minutes_and_seconds() {
+        assert_eq!(timestamp("1:23:00"), Ok(Duration::from_secs(4980)));
+        assert_eq!(timestamp("23:00"), Ok(Duration::from_secs(1380)));
+        assert_eq!(timestamp("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(timestamp(" 0:01:05 "), Ok(Duration::from_secs(65)));
+    }
+
+    #[test]
+    fn timestamp_keeps_fractions() {
+        assert_eq!(timestamp("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(timestamp("1:02.25"), Ok(Duration::from_millis(62_250)));
+    }
+
+    #[test]
+    fn timestamp_rejects_malformed_times() {
+        for input in ["", "abc", "1::30", "1:2:3:4", "-5", "1:-30", "inf", "NaN"] {
+            assert!(timestamp(input).is_err(), "{input:?} was accepted");
+        }
+    }
+
+    #[test]
+    fn speed_accepts_the_range_inclusively() {
+        assert_eq!(speed("0.25"), Ok(0.25));
+        assert_eq!(speed(" 1.25 "), Ok(1.25));
+        assert_eq!(speed("4"), Ok(4.0));
+    }
+
+    #[test]
+    fn speed_rejects_anything_else() {
+        for input in ["0.2", "4.01", "0", "-1", "fast", ""] {
+            assert!(speed(input).is_err(), "{input:?} was accepted");
+        }
+    }
+}
@@ -0,0 +1,676 @@
+//! Settings persisted between sessions.
+use iced::{Color, Font};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::*;
+use crate::i18n::Language;
+use crate::t;
+use crate::utils::ViewType;
+use crate::video::{Bookmark, Loudness, Palette, Series};
+
+const APP: &str = "kino";
+
+/// The subtitles picked for a video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SubtitleChoice {
+    Off,
+    /// A subtitle stream of the container, by index.
+    Embedded(i32),
+    /// A subtitle file on disk.
+    External(PathBuf),
+}
+
+/// Choices remembered for a single video.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VideoConfig {
+    /// The audio stream, by index.
+    pub audio: Option<i32>,
+    pub subtitles: Option<SubtitleChoice>,
+    /// The subtitles shown at the top of the video.
+    pub secondary_subtitles: Option<SubtitleChoice>,
+    /// How late subtitles are shown, in milliseconds.
+    pub subtitle_delay: i64,
+    /// How late the audio is played, in milliseconds.
+    pub audio_delay: i64,
+    pub bookmarks: Vec<Bookmark>,
+    /// Where the end credits start, in milliseconds, once marked.
+    pub credits: Option<u64>,
+    /// Overrides the global picture adjustments for this video.
+    pub adjustments: Option<Adjustments>,
+    pub framing: Framing,
+    /// The black bars burnt into the picture, once looked for.
+    pub detected_crop: Option<Crop>,
+    /// The loudness of the audio, once analysed.
+    pub loudness: Option<Loudness>,
+    /// The colours of the artwork, once picked.
+    pub palette: Option<Palette>,
+}
+
+impl VideoConfig {
+    /// Loads the stored choices for `video`, falling back to the defaults.
+    pub fn load(video: impl AsRef<Path>) -> Self {
+        match read_videos() {
+            Ok(mut videos) => videos.remove(&key(video)).unwrap_or_default(),
+            Err(err) => {
+                eprintln!("Error loading video settings: \n{err}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Changes the stored choices for `video` in place, keeping whatever else
+    /// was saved for it since it was loaded.
+    pub fn update(video: impl AsRef<Path>, change: impl FnOnce(&mut Self)) -> Result<()> {
+        let mut videos = read_videos()?;
+        change(videos.entry(key(video)).or_default());
+
+        write(&videos_path()?, &videos)
+    }
+}
+
+/// When subtitles are turned on automatically.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SubtitleMode {
+    /// Always show subtitles in a preferred language.
+    Always,
+    /// Show full subtitles when the audio is in a language that is not
+    /// preferred for subtitles, and only forced ones otherwise.
+    #[default]
+    Foreign,
+    /// Only ever show forced subtitles.
+    ForcedOnly,
+    Off,
+}
+
+impl SubtitleMode {
+    pub const ALL: [Self; 4] = [Self::Always, Self::Foreign, Self::ForcedOnly, Self::Off];
+}
+
+impl Display for SubtitleMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Always => t!("subtitles-always"),
+            Self::Foreign => t!("subtitles-foreign"),
+            Self::ForcedOnly => t!("subtitles-forced-only"),
+            Self::Off => t!("subtitles-off"),
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Rules for picking tracks when a video is first opened.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Languages {
+    /// Audio languages, most preferred first.
+    pub audio: Vec<String>,
+    /// Subtitle languages, most preferred first.
+    pub subtitles: Vec<String>,
+    pub subtitle_mode: SubtitleMode,
+}
+
+/// How subtitles are drawn over the video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubtitleStyle {
+    /// A font family installed on the system, the default font when unset.
+    pub font: Option<String>,
+    /// The height of the text as a fraction of the video height.
+    pub size: f32,
+    /// A hex colour such as `#ffffff`.
+    pub color: String,
+    /// The width of the outline in pixels, none when zero.
+    pub outline: f32,
+    pub outline_color: String,
+    /// The opacity of the box behind the text, none when zero.
+    pub background: f32,
+    /// The distance from the edge of the video as a fraction of its height.
+    pub position: f32,
+}
+
+impl SubtitleStyle {
+    /// Resolves the font family.
+    ///
+    /// iced only accepts static family names so the name is leaked; call this
+    /// once per change rather than on every frame.
+    pub fn font(&self) -> Font {
+        match self.font.as_ref() {
+            Some(name) => Font::with_name(Box::leak(name.clone().into_boxed_str())),
+            None => Font::DEFAULT,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        Color::parse(&self.color).unwrap_or(Color::WHITE)
+    }
+
+    pub fn outline_color(&self) -> Color {
+        Color::parse(&self.outline_color).unwrap_or(Color::BLACK)
+    }
+}
+
+impl Default for SubtitleStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            size: 0.05,
+            color: "#ffffff".into(),
+            outline: 0.0,
+            outline_color: "#000000".into(),
+            background: 0.6,
+            position: 0.06,
+        }
+    }
+}
+
+/// Corrections applied to the picture.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Adjustments {
+    /// From -1 to 1.
+    pub brightness: f32,
+    /// From 0 to 2.
+    pub contrast: f32,
+    /// From 0 to 2.
+    pub saturation: f32,
+    /// From -1 to 1, rotating the colour wheel by up to half a turn.
+    pub hue: f32,
+    /// The gamma of the video renderer.
+    pub gamma: f32,
+}
+
+impl Adjustments {
+    pub const BRIGHTNESS: RangeInclusive<f32> = -1.0..=1.0;
+    pub const CONTRAST: RangeInclusive<f32> = 0.0..=2.0;
+    pub const SATURATION: RangeInclusive<f32> = 0.0..=2.0;
+    pub const HUE: RangeInclusive<f32> = -1.0..=1.0;
+    pub const GAMMA: RangeInclusive<f32> = 0.5..=3.0;
+}
+
+impl Default for Adjustments {
+    fn default() -> Self {
+        Self {
+            brightness: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            hue: 0.0,
+            gamma: 1.5,
+        }
+    }
+}
+
+/// The shape the picture is shown in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AspectRatio {
+    /// The shape of the video itself.
+    #[default]
+    Auto,
+    Standard,
+    Wide,
+    Scope,
+    /// Width over height.
+    Custom(f32),
+}
+
+impl AspectRatio {
+    pub const PRESETS: [Self; 4] = [Self::Auto, Self::Standard, Self::Wide, Self::Scope];
+
+    /// Width over height, `None` when following the video.
+    pub fn ratio(&self) -> Option<f32> {
+        match self {
+            Self::Auto => None,
+            Self::Standard => Some(4.0 / 3.0),
+            Self::Wide => Some(16.0 / 9.0),
+            Self::Scope => Some(2.35),
+            Self::Custom(ratio) => Some(*ratio),
+        }
+    }
+
+    /// Parses a custom ratio written as `21:9` or `1.85`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let ratio = match input.split_once(':') {
+            Some((width, height)) => {
+                width.trim().parse::<f32>().ok()? / height.trim().parse::<f32>().ok()?
+            }
+            None => input.trim().parse().ok()?,
+        };
+
+        (ratio.is_finite() && ratio > 0.0).then_some(Self::Custom(ratio))
+    }
+}
+
+impl Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "{}", t!("aspect-auto")),
+            Self::Standard => write!(f, "4:3"),
+            Self::Wide => write!(f, "16:9"),
+            Self::Scope => write!(f, "2.35:1"),
+            Self::Custom(ratio) => write!(f, "{ratio:.2}:1"),
+        }
+    }
+}
+
+/// How the picture is sized within the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Fit {
+    /// The whole picture is shown.
+    #[default]
+    Contain,
+    /// The window is filled, cutting off the edges of the picture.
+    Cover,
+    /// The window is filled, distorting the picture.
+    Stretch,
+    /// One pixel of the video to one pixel of the window.
+    Original,
+}
+
+impl Fit {
+    pub const ALL: [Self; 4] = [Self::Contain, Self::Cover, Self::Stretch, Self::Original];
+}
+
+impl Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Contain => t!("fit-contain"),
+                Self::Cover => t!("fit-cover"),
+                Self::Stretch => t!("fit-stretch"),
+                Self::Original => t!("fit-original"),
+            }
+        )
+    }
+}
+
+/// The fractions of the picture cut off at each edge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Crop {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Crop {
+    /// The most that can be cut off an edge.
+    pub const MAX: f32 = 0.4;
+}
+
+/// How the picture is placed in the window.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Framing {
+    pub aspect: AspectRatio,
+    pub fit: Fit,
+    pub zoom: f32,
+    /// How far the zoomed picture is moved, from -1 to 1 of the part that
+    /// does not fit in the window.
+    pub pan_x: f32,
+    pub pan_y: f32,
+    pub crop: Crop,
+}
+
+impl Framing {
+    pub const ZOOM: RangeInclusive<f32> = 0.5..=8.0;
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for Framing {
+    fn default() -> Self {
+        Self {
+            aspect: AspectRatio::Auto,
+            fit: Fit::Contain,
+            zoom: 1.0,
+            pan_x: 0.0,
+            pan_y: 0.0,
+            crop: Crop::default(),
+        }
+    }
+}
+
+/// A set of equalizer gains.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EqualizerPreset {
+    #[default]
+    Flat,
+    Bass,
+    Treble,
+    /// Brings dialogue forward.
+    Voice,
+    Cinema,
+    /// Gains set band by band.
+    Custom,
+}
+
+impl EqualizerPreset {
+    pub const ALL: [Self; 6] = [
+        Self::Flat,
+        Self::Bass,
+        Self::Treble,
+        Self::Voice,
+        Self::Cinema,
+        Self::Custom,
+    ];
+
+    /// The gain of each band in decibels, `None` for custom gains.
+    pub fn bands(&self) -> Option<[f32; 10]> {
+        match self {
+            Self::Flat => Some([0.0; 10]),
+            Self::Bass => Some([6.0, 5.0, 4.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+            Self::Treble => Some([0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 4.0, 5.0, 6.0]),
+            Self::Voice => Some([-3.0, -2.0, -1.0, 0.0, 2.0, 4.0, 4.0, 2.0, 0.0, -1.0]),
+            Self::Cinema => Some([4.0, 3.0, 1.0, 0.0, -1.0, -1.0, 0.0, 2.0, 3.0, 3.0]),
+            Self::Custom => None,
+        }
+    }
+}
+
+impl Display for EqualizerPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Flat => t!("equalizer-flat"),
+                Self::Bass => t!("equalizer-bass"),
+                Self::Treble => t!("equalizer-treble"),
+                Self::Voice => t!("equalizer-voice"),
+                Self::Cinema => t!("equalizer-cinema"),
+                Self::Custom => t!("equalizer-custom"),
+            }
+        )
+    }
+}
+
+/// Processing of the sound before it is played.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioFilters {
+    /// Narrows the gap between quiet and loud sounds, for watching at night.
+    pub night_mode: bool,
+    /// Evens out loudness between files from their ReplayGain tags, or their
+    /// measured loudness.
+    pub normalize: bool,
+    pub preset: EqualizerPreset,
+    /// The gain of each equalizer band in decibels, from 29 Hz to 15 kHz.
+    pub bands: [f32; 10],
+}
+
+impl AudioFilters {
+    pub const BAND: RangeInclusive<f32> = -12.0..=12.0;
+}
+
+/// Where and how frames of the video are saved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Screenshots {
+    /// The folder screenshots are saved to, `Pictures/kino` when unset.
+    pub folder: Option<PathBuf>,
+    /// The file name, where `{name}` is replaced by the name of the video and
+    /// `{hh-mm-ss.mmm}` by the position of the frame.
+    pub template: String,
+    /// Whether the shown subtitles are drawn into screenshots.
+    pub burn_subtitles: bool,
+}
+
+impl Screenshots {
+    /// The path of a screenshot of `name` taken at `position`.
+    pub fn path(&self, name: &str, position: Duration) -> Result<PathBuf> {
+        let folder = match self.folder.clone() {
+            Some(folder) => folder,
+            None => dirs::picture_dir()
+                .map(|dir| dir.join(APP))
+                .ok_or(Error::MissingDirectory)?,
+        };
+
+        let millis = position.as_millis();
+        let secs = millis / 1000;
+        let timestamp = format!(
+            "{:02}-{:02}-{:02}.{:03}",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+            millis % 1000
+        );
+
+        let file = self
+            .template
+            .replace("{name}", &name.replace(['/', '\\'], "_"))
+            .replace("{hh-mm-ss.mmm}", &timestamp);
+
+        Ok(folder.join(file))
+    }
+}
+
+impl Default for Screenshots {
+    fn default() -> Self {
+        Self {
+            folder: None,
+            template: "{name}_{hh-mm-ss.mmm}.png".into(),
+            burn_subtitles: false,
+        }
+    }
+}
+
+/// How far the playback keys move the video, its speed and volume.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Playback {
+    /// Seconds skipped by a seek.
+    pub seek: f64,
+    /// Seconds skipped by a seek while shift is held.
+    pub long_seek: f64,
+    pub speed_step: f64,
+    /// From 0 to 1.
+    pub volume_step: f64,
+    /// The volume videos start at, from 0 to 1.
+    pub default_volume: f64,
+    /// The share of a video after which it counts as watched.
+    pub completion: f32,
+}
+
+impl Playback {
+    pub const SEEK: RangeInclusive<f64> = 1.0..=60.0;
+    pub const LONG_SEEK: RangeInclusive<f64> = 5.0..=300.0;
+    pub const SPEED_STEP: RangeInclusive<f64> = 0.05..=0.5;
+    pub const VOLUME_STEP: RangeInclusive<f64> = 0.01..=0.2;
+    pub const COMPLETION: RangeInclusive<f32> = 0.5..=1.0;
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            seek: 10.0,
+            long_seek: 20.0,
+            speed_step: 0.1,
+            volume_step: 0.05,
+            default_volume: 1.0,
+            completion: 0.9,
+        }
+    }
+}
+
+/// Where videos are looked for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Library {
+    pub folders: Vec<PathBuf>,
+    /// Whether the folders inside the library folders are looked in too.
+    pub recursive: bool,
+    /// Whether files and folders starting with a dot are included.
+    pub hidden: bool,
+}
+
+impl Default for Library {
+    fn default() -> Self {
+        Self {
+            folders: vec![],
+            recursive: true,
+            hidden: false,
+        }
+    }
+}
+
+/// The colours of the interface.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeChoice {
+    /// Light or dark, following the system.
+    #[default]
+    System,
+    Light,
+    Dark,
+    /// A palette in the themes folder, by its file name.
+    Custom(String),
+}
+
+impl Display for ThemeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::System => write!(f, "{}", t!("theme-system")),
+            Self::Light => write!(f, "{}", t!("theme-light")),
+            Self::Dark => write!(f, "{}", t!("theme-dark")),
+            Self::Custom(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// The look of the interface.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Appearance {
+    /// The language of the interface.
+    pub language: Language,
+    pub theme: ThemeChoice,
+    /// The layout the library opens with.
+    pub view: ViewType,
+    /// How much larger than normal everything is drawn, such as on a TV.
+    pub ui_scale: f64,
+    /// How much larger than normal text, icons and cards are drawn.
+    pub text_scale: f32,
+}
+
+impl Appearance {
+    pub const UI_SCALE: RangeInclusive<f64> = 0.75..=2.5;
+    pub const TEXT_SCALE: RangeInclusive<f32> = 0.8..=1.75;
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            theme: ThemeChoice::default(),
+            view: ViewType::default(),
+            ui_scale: 1.0,
+            text_scale: 1.0,
+        }
+    }
+}
+
+/// Global settings, stored in the user's config directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub playback: Playback,
+    pub library: Library,
+    pub appearance: Appearance,
+    pub languages: Languages,
+    pub subtitles: SubtitleStyle,
+    pub screenshots: Screenshots,
+    /// Picture adjustments for videos without their own.
+    pub adjustments: Adjustments,
+    /// Whether black bars burnt into videos are cropped away.
+    pub auto_crop: bool,
+    pub audio: AudioFilters,
+    /// Language rules overriding `languages` for a series, keyed by its name.
+    pub series: BTreeMap<String, Languages>,
+}
+
+impl Config {
+    /// Loads the global settings, falling back to the defaults.
+    pub fn load() -> Self {
+        match config_path().and_then(|path| read(&path)) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("Error loading settings: \n{err}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        write(&config_path()?, self)
+    }
+
+    /// The language rules to follow for a video of `series`.
+    pub fn languages(&self, series: Option<&Series>) -> &Languages {
+        series
+            .and_then(|series| self.series.get(&series.name))
+            .unwrap_or(&self.languages)
+    }
+}
+
+fn key(video: impl AsRef<Path>) -> String {
+    let video = video.as_ref();
+
+    video
+        .canonicalize()
+        .unwrap_or_else(|_| video.to_owned())
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn videos_path() -> Result<PathBuf> {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP).join("videos.toml"))
+        .ok_or(Error::MissingDirectory)
+}
+
+fn config_path() -> Result<PathBuf> {
+    config_file("config.toml")
+}
+
+/// The path of `name` in kino's folder of the user's config directory.
+pub(crate) fn config_file(name: &str) -> Result<PathBuf> {
+    dirs::config_dir()
+        .map(|dir| dir.join(APP).join(name))
+        .ok_or(Error::MissingDirectory)
+}
+
+fn read_videos() -> Result<BTreeMap<String, VideoConfig>> {
+    read(&videos_path()?)
+}
+
+/// Reads a toml file, treating a missing one as empty.
+pub(crate) fn read<T: Default + for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(toml::from_str(&content)?),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+pub(crate) fn write<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, toml::to_string(value)?)?;
+    Ok(())
+}
@@ -8,7 +8,19 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     GStreamerError(GStreamerError),
     ThumbnailEmptyVideo,
+    UnsupportedSubtitle(std::path::PathBuf),
+    /// The file has no audio to measure.
+    NoAudio,
+    /// No picture could be decoded from the file.
+    NoPicture,
+    /// The platform has no standard location for config or data files.
+    MissingDirectory,
+    /// The path can't be turned into a URL to play from.
+    InvalidPath(std::path::PathBuf),
+    Video(iced_video_player::Error),
     IO(std::io::Error),
+    Deserialize(toml::de::Error),
+    Serialize(toml::ser::Error),
 }
 
 impl Display for Error {
@@ -16,7 +28,17 @@ impl Display for Error {
         match self {
             Self::GStreamerError(error) => error.fmt(f),
             Self::IO(error) => error.fmt(f),
+            Self::Deserialize(error) => error.fmt(f),
+            Self::Serialize(error) => error.fmt(f),
+            Self::Video(error) => error.fmt(f),
+            Self::MissingDirectory => write!(f, "Could not find a directory for kino's files"),
             Self::ThumbnailEmptyVideo => write!(f, "Tried creating a thumbnail for an empty Video"),
+            Self::NoAudio => write!(f, "No audio was found to measure"),
+            Self::NoPicture => write!(f, "No picture could be decoded"),
+            Self::UnsupportedSubtitle(path) => {
+                write!(f, "Unsupported subtitle format: {}", path.display())
+            }
+            Self::InvalidPath(path) => write!(f, "Not a playable path: {}", path.display()),
         }
     }
 }
@@ -26,7 +48,15 @@ impl std::error::Error for Error {
         match self {
             Self::GStreamerError(error) => error.source(),
             Self::IO(error) => error.source(),
-            Self::ThumbnailEmptyVideo => None,
+            Self::Deserialize(error) => error.source(),
+            Self::Serialize(error) => error.source(),
+            Self::Video(error) => error.source(),
+            Self::ThumbnailEmptyVideo
+            | Self::UnsupportedSubtitle(_)
+            | Self::InvalidPath(_)
+            | Self::MissingDirectory
+            | Self::NoAudio
+            | Self::NoPicture => None,
         }
     }
 }
@@ -37,6 +67,24 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl From<iced_video_player::Error> for Error {
+    fn from(value: iced_video_player::Error) -> Self {
+        Self::Video(value)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(value: toml::de::Error) -> Self {
+        Self::Deserialize(value)
+    }
+}
+
+impl From<toml::ser::Error> for Error {
+    fn from(value: toml::ser::Error) -> Self {
+        Self::Serialize(value)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum GStreamerError {
     Glib(glib::Error),
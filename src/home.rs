@@ -1,4 +1,13 @@
+use crate::cli::{Args, Source};
+use crate::config::{Config, VideoConfig};
+use crate::error;
+use crate::i18n;
+use crate::keymap::{Action, Chord, Keymap, Scope};
+use crate::player::{BookmarkMessage, Player, PlayerMessage, Queue};
+use crate::t;
+use crate::theme;
 use crate::utils::{self, load_fonts};
+use crate::video::{Loudness, Video, VideoId};
 use iced::{
     Element, Length, Padding, Subscription, Task, Theme,
     alignment::Vertical,
@@ -12,12 +21,18 @@ use iced::{
     },
     window,
 };
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
 
 mod movies;
 mod pages;
+mod settings;
 
-use movies::{Movies, MoviesMessage};
-use pages::{Page, PageKind, PageUpdate};
+use movies::{Movies, MoviesMessage, QueueAction, Thumbnail};
+pub use pages::PageKind;
+use pages::{Page, PageUpdate};
+use settings::{Settings, SettingsMessage};
 use utils::empty;
 use utils::filter::*;
 use utils::icons;
@@ -52,13 +67,21 @@ pub enum HomeMessage {
     ToggleFilter,
     Filter(FilterMessage),
     Movies(MoviesMessage),
-    Settings,
+    Player(PlayerMessage),
+    PlayQueue,
+    Settings(SettingsMessage),
+    /// A key press outside of the player, resolved through the keymap.
+    Chord(Chord),
     Randomize,
     Back,
     Forward,
     ToggleView,
     Home,
     Goto(PageKind),
+    /// The videos found by scanning the library again.
+    Library(Vec<Thumbnail>),
+    /// The measured loudness of a file, `None` when it could not be measured.
+    Loudness(PathBuf, Option<Loudness>),
     NewCollection,
     Animate,
     None,
@@ -74,29 +97,105 @@ pub struct Home {
     show_sorts: bool,
     show_filters: bool,
     filters: Filter,
+    player: Option<Player>,
+    /// Videos queued up while browsing, handed to the player once it opens.
+    queue: Queue,
+    /// Whether the player was opened on the queue, which it hands back on leaving.
+    playing_queue: bool,
+    settings: Config,
+    theme: Theme,
+    keymap: Keymap,
+    /// The file whose loudness is being measured.
+    measuring: Option<PathBuf>,
+    /// Files measured this session, or failed to be.
+    measured: HashSet<PathBuf>,
 }
 
 impl Home {
-    pub fn boot() -> (Self, Task<HomeMessage>) {
+    pub fn boot(args: Args) -> (Self, Task<HomeMessage>) {
         let load_font = load_fonts().map(HomeMessage::FontLoad);
+        let mut home = Self::new(Config::load(), FilterMode::default());
 
-        (
-            Self::new(ViewType::default(), FilterMode::default()),
-            load_font,
-        )
+        if let Some(kind) = args.sort {
+            home.sort = Sort {
+                kinds: vec![kind],
+                ..Sort::default()
+            };
+        }
+
+        let task = match (args.source.clone(), args.page) {
+            (Some(source), _) => home.open(source, &args),
+            (None, Some(page)) => home.update(HomeMessage::Goto(page), Instant::now()),
+            (None, None) => Task::none(),
+        };
+
+        (home, Task::batch([load_font, task]))
     }
 
-    fn new(view: ViewType, filter_mode: FilterMode) -> Self {
+    /// Plays a video given on the command line, outside of the library.
+    fn open(&mut self, source: Source, args: &Args) -> Task<HomeMessage> {
+        let video = match source {
+            Source::File(path) => Video::from_path(0, path),
+            Source::Url(url) => Video::from_url(0, url),
+        };
+        let (id, name) = (video.id, video.name.clone());
+        let task = match self.try_play(Queue::new([video], id)) {
+            Ok(task) => task,
+            // The library was not asked for, so there is nothing to fall back to.
+            Err(err) => {
+                eprintln!("Error opening {name}: \n{err}");
+                std::process::exit(1);
+            }
+        };
+
+        let Some(player) = self.player.as_mut() else {
+            return task;
+        };
+        if let Some(start) = args.start {
+            let duration = player.duration();
+            if !duration.is_zero() && start >= duration {
+                eprintln!(
+                    "Error opening {name}: \nit is {:.1}s long, so it cannot start at {:.1}s",
+                    duration.as_secs_f64(),
+                    start.as_secs_f64()
+                );
+                std::process::exit(1);
+            }
+            player.start_at(start);
+        }
+        if let Some(speed) = args.speed {
+            player.set_speed(speed);
+        }
+
+        if args.fullscreen {
+            let fullscreen = Task::done(HomeMessage::Player(PlayerMessage::ToggleFullscreen));
+            return Task::batch([task, fullscreen]);
+        }
+        task
+    }
+
+    fn new(settings: Config, filter_mode: FilterMode) -> Self {
+        i18n::set_language(settings.appearance.language);
+        typo::set_scale(settings.appearance.text_scale);
+
         Self {
             forward: vec![],
             backward: vec![],
             search: String::default(),
-            view,
+            view: settings.appearance.view,
             sort: Sort::default(),
             show_sorts: false,
             show_filters: false,
             now: Instant::now(),
             filters: Filter::new(filter_mode),
+            player: None,
+            queue: Queue::default(),
+            playing_queue: false,
+            theme: theme::resolve(&settings.appearance.theme),
+            settings,
+            keymap: Keymap::load(),
+            measuring: None,
+            measured: HashSet::default(),
         }
     }
 
@@ -114,7 +213,30 @@ impl Home {
                 self.search = input;
                 Task::none()
             }
-            HomeMessage::Settings => Task::none(),
+            HomeMessage::Settings(message) => {
+                let Some(page) = self.current_page_mut() else {
+                    return Task::none();
+                };
+
+                let task = page.settings_update(message).map(HomeMessage::Settings);
+                if let Page::Settings(settings) = page {
+                    let (config, keymap) = (settings.config().clone(), settings.keymap().clone());
+                    self.keymap = keymap;
+                    let rescan = self.apply_settings(config);
+                    return Task::batch([task, rescan]);
+                }
+                task
+            }
+            HomeMessage::Chord(chord) => match self.keymap.action(&chord, Scope::Library) {
+                Some(Action::Back) => self.update(HomeMessage::Back, now),
+                Some(Action::Forward) => self.update(HomeMessage::Forward, now),
+                Some(Action::PlayQueue) => self.update(HomeMessage::PlayQueue, now),
+                Some(Action::ToggleView) => self.update(HomeMessage::ToggleView, now),
+                Some(Action::Randomize) => self.update(HomeMessage::Randomize, now),
+                Some(Action::Home) => self.update(HomeMessage::Home, now),
+                Some(Action::Settings) => self.update(HomeMessage::Goto(PageKind::Settings), now),
+                _ => Task::none(),
+            },
             HomeMessage::Home => {
                 self.forward.clear();
                 std::mem::swap(&mut self.forward, &mut self.backward);
@@ -127,12 +249,19 @@ impl Home {
                             self.sort.clone(),
                             self.filters,
                             matches!(self.view, ViewType::Grid),
+                            &self.settings,
                         );
                         self.forward.clear();
                         self.backward.push(Page::Movies(movies));
 
                         task.map(HomeMessage::Movies)
                     }
+                    PageKind::Settings => {
+                        self.forward.clear();
+                        self.backward.push(Page::Settings(Settings::new()));
+
+                        Task::none()
+                    }
                     _ => {
                         todo!()
 
@@ -142,6 +271,152 @@ impl Home {
                     }
                 }
             }
+            HomeMessage::Movies(MoviesMessage::Play(id)) => {
+                let Some(page) = self.current_page() else {
+                    return Task::none();
+                };
+
+                let queue = Queue::new(page.videos(), id);
+                self.play(queue)
+            }
+            HomeMessage::Movies(MoviesMessage::PlayFrom(id, position)) => {
+                let Some(page) = self.current_page() else {
+                    return Task::none();
+                };
+
+                let queue = Queue::new(page.videos(), id);
+                let task = self.play(queue);
+
+                if let Some(player) = self.player.as_mut() {
+                    player.start_at(Duration::from_secs_f64(position));
+                }
+                task
+            }
+            HomeMessage::Movies(MoviesMessage::Queue(action)) => {
+                let Some(page) = self.current_page() else {
+                    return Task::none();
+                };
+
+                match action {
+                    QueueAction::PlayNext(id) => {
+                        if let Some(video) = page.video(id).cloned() {
+                            self.queue.play_next(video);
+                        }
+                        Task::none()
+                    }
+                    QueueAction::Add(id) => {
+                        if let Some(video) = page.video(id).cloned() {
+                            self.queue.add(video);
+                        }
+                        Task::none()
+                    }
+                    QueueAction::PlaySeason(id) => {
+                        let Some(video) = page.video(id).cloned() else {
+                            return Task::none();
+                        };
+
+                        let queue = Queue::season(page.all_videos(), &video);
+                        self.play(queue)
+                    }
+                }
+            }
+            HomeMessage::PlayQueue => {
+                if self.queue.is_empty() {
+                    return Task::none();
+                }
+
+                let task = self.play(self.queue.clone());
+                self.playing_queue = self.player.is_some();
+                task
+            }
+            HomeMessage::Player(PlayerMessage::Chord(chord)) => {
+                // Resolved here so the actions Home handles itself, such as
+                // leaving the player, go through it.
+                let Some(player) = self.player.as_ref() else {
+                    return Task::none();
+                };
+
+                match player.resolve(&chord) {
+                    Some(message) => self.update(HomeMessage::Player(message), now),
+                    None => Task::none(),
+                }
+            }
+            HomeMessage::Player(PlayerMessage::PreviousScreen) => {
+                let Some(mut player) = self.player.take() else {
+                    return Task::none();
+                };
+
+                let task = player.update(PlayerMessage::ExitFullscreen);
+                if std::mem::take(&mut self.playing_queue) {
+                    self.queue = player.into_queue();
+                }
+
+                task.map(HomeMessage::Player)
+            }
+            HomeMessage::Player(PlayerMessage::UseAsPoster(path)) => {
+                let Some(player) = self.player.as_mut() else {
+                    return Task::none();
+                };
+
+                if let Some(id) = player.current_video().map(|video| video.id) {
+                    let poster = path.to_string_lossy().into_owned();
+                    self.edit_video(id, |video| video.poster = Some(poster.clone()));
+                }
+
+                player
+                    .update(PlayerMessage::UseAsPoster(path))
+                    .map(HomeMessage::Player)
+            }
+            HomeMessage::Player(PlayerMessage::MarkCredits) => {
+                let Some(player) = self.player.as_mut() else {
+                    return Task::none();
+                };
+
+                let task = player
+                    .update(PlayerMessage::MarkCredits)
+                    .map(HomeMessage::Player);
+
+                if let Some(video) = player.current_video() {
+                    let (id, credits) = (video.id, video.credits);
+                    self.update_video(id, |video| video.credits = credits);
+                }
+                task
+            }
+            // Renames are only reflected once finished.
+            HomeMessage::Player(
+                message @ PlayerMessage::Bookmark(
+                    BookmarkMessage::Add
+                    | BookmarkMessage::FinishRename
+                    | BookmarkMessage::Delete(_),
+                ),
+            ) => {
+                let Some(player) = self.player.as_mut() else {
+                    return Task::none();
+                };
+
+                let task = player.update(message).map(HomeMessage::Player);
+
+                if let Some(video) = player.current_video() {
+                    let (id, bookmarks) = (video.id, video.bookmarks.clone());
+                    self.update_video(id, |video| video.bookmarks = bookmarks.clone());
+                }
+                task
+            }
+            HomeMessage::Player(message) => {
+                let Some(player) = self.player.as_mut() else {
+                    return Task::none();
+                };
+
+                player.update(message).map(HomeMessage::Player)
+            }
+            HomeMessage::Movies(message @ MoviesMessage::Thumbnails(_)) => {
+                let Some(page) = self.current_page_mut() else {
+                    return Task::none();
+                };
+
+                let task = page.movies_update(message, now).map(HomeMessage::Movies);
+                Task::batch([task, self.measure_next()])
+            }
             HomeMessage::Movies(message) => {
                 let Some(page) = self.current_page_mut() else {
                     return Task::none();
@@ -149,6 +424,31 @@ impl Home {
 
                 page.movies_update(message, now).map(HomeMessage::Movies)
             }
+            HomeMessage::Library(thumbnails) => {
+                for page in self.backward.iter_mut().chain(self.forward.iter_mut()) {
+                    page.set_thumbnails(thumbnails.clone());
+                }
+
+                self.measure_next()
+            }
+            HomeMessage::Loudness(path, loudness) => {
+                self.measuring = None;
+
+                if let Some(loudness) = loudness {
+                    let result =
+                        VideoConfig::update(&path, |config| config.loudness = Some(loudness));
+                    if let Err(err) = result {
+                        eprintln!("Error saving video settings: \n{err}");
+                    }
+
+                    for page in self.backward.iter_mut().chain(self.forward.iter_mut()) {
+                        page.set_loudness(&path, loudness);
+                    }
+                }
+
+                self.measured.insert(path);
+                self.measure_next()
+            }
             HomeMessage::Back => {
                 if self
                     .current_page_mut()
@@ -382,6 +682,66 @@ impl Home {
         }
     }
 
+    /// Opens the player on `queue`.
+    fn play(&mut self, queue: Queue) -> Task<HomeMessage> {
+        self.try_play(queue).unwrap_or_else(|err| {
+            eprintln!("Error opening video: \n{err}");
+            Task::none()
+        })
+    }
+
+    fn try_play(&mut self, queue: Queue) -> error::Result<Task<HomeMessage>> {
+        if queue.is_empty() {
+            return Ok(Task::none());
+        }
+
+        let (player, task) = Player::new(queue)?;
+        self.player = Some(player);
+        self.playing_queue = false;
+
+        Ok(task.map(HomeMessage::Player))
+    }
+
+    /// Brings the open pages in line with changed settings, scanning the
+    /// library again when where videos are looked for changed.
+    fn apply_settings(&mut self, settings: Config) -> Task<HomeMessage> {
+        let mut updates = vec![];
+
+        if settings.appearance.view != self.settings.appearance.view {
+            self.view = settings.appearance.view;
+            updates.push(PageUpdate::Layout(self.view));
+        }
+        if settings.appearance.theme != self.settings.appearance.theme {
+            self.theme = theme::resolve(&settings.appearance.theme);
+        }
+        i18n::set_language(settings.appearance.language);
+        typo::set_scale(settings.appearance.text_scale);
+        if settings.playback.completion != self.settings.playback.completion {
+            updates.push(PageUpdate::Completion(settings.playback.completion));
+        }
+
+        for update in updates {
+            for page in self.backward.iter_mut().chain(self.forward.iter_mut()) {
+                page.page_update(update.clone(), self.now);
+            }
+        }
+
+        let rescan = settings.library != self.settings.library
+            && self
+                .backward
+                .iter()
+                .chain(self.forward.iter())
+                .any(Page::is_movies);
+        let task = if rescan {
+            Movies::scan(settings.library.clone()).map(HomeMessage::Library)
+        } else {
+            Task::none()
+        };
+
+        self.settings = settings;
+        task
+    }
+
     fn current_page(&self) -> Option<&Page> {
         self.backward.last()
     }
@@ -390,10 +750,66 @@ impl Home {
         self.backward.last_mut()
     }
 
+    /// Measures the loudness of the next library file without one. Files are
+    /// measured one at a time, however many pages are open, so browsing stays
+    /// responsive.
+    fn measure_next(&mut self) -> Task<HomeMessage> {
+        if self.measuring.is_some() {
+            return Task::none();
+        }
+
+        let Some(path) = self
+            .backward
+            .iter()
+            .chain(self.forward.iter())
+            .find_map(|page| {
+                page.find_video(|video| {
+                    video.loudness.is_none()
+                        && !video.is_stream()
+                        && !self.measured.contains(&video.path)
+                })
+            })
+            .map(|video| video.path.clone())
+        else {
+            return Task::none();
+        };
+
+        self.measuring = Some(path.clone());
+
+        Task::perform(
+            tokio::task::spawn_blocking({
+                let path = path.clone();
+                move || utils::loudness::analyze(path)
+            }),
+            move |res| match res {
+                Ok(Ok(loudness)) => HomeMessage::Loudness(path, Some(loudness)),
+                Ok(Err(err)) => {
+                    eprintln!("Error measuring loudness: \n{err}");
+                    HomeMessage::Loudness(path, None)
+                }
+                Err(_) => HomeMessage::Loudness(path, None),
+            },
+        )
+    }
+
+    /// Applies `edit` to the library record of a video, wherever it is shown.
+    fn edit_video(&mut self, id: VideoId, edit: impl Fn(&mut Video)) {
+        for page in self.backward.iter_mut().chain(self.forward.iter_mut()) {
+            page.edit_video(id, &edit);
+        }
+    }
+
+    /// Like [`Home::edit_video`], without reloading the artwork.
+    fn update_video(&mut self, id: VideoId, edit: impl Fn(&mut Video)) {
+        for page in self.backward.iter_mut().chain(self.forward.iter_mut()) {
+            page.update_video(id, &edit);
+        }
+    }
+
     fn side(&self) -> Element<'_, HomeMessage> {
         let header = {
-            let icon = icons::icon(icons::LOGO).size(H2);
-            let text = text("Kino").size(H2);
+            let icon = icons::icon(icons::LOGO).size(h2());
+            let text = text("Kino").size(h2());
 
             row!(icon, text)
                 .padding([5, 10])
@@ -404,29 +820,39 @@ impl Home {
         let collections = column!(
             icon_button(
                 icons::HOME,
-                "Home",
+                t!("page-home"),
                 HomeMessage::Home,
                 self.current_page().is_none()
             ),
             icon_button(
                 icons::SHOW,
-                "Shows",
+                t!("page-shows"),
                 HomeMessage::Goto(Page::goto_shows()),
                 self.current_page().map(Page::is_shows).unwrap_or_default()
             ),
             icon_button(
                 icons::MOVIE,
-                "Movies",
+                t!("page-movies"),
                 HomeMessage::Goto(Page::goto_movies()),
                 self.current_page().map(Page::is_movies).unwrap_or_default(),
             ),
             icon_button(
                 icons::NEW_COLLECTION,
-                "New collection",
+                t!("page-new-collection"),
                 HomeMessage::NewCollection,
                 false
             ),
         )
+        .push(if self.queue.is_empty() {
+            empty()
+        } else {
+            icon_button(
+                icons::LIST,
+                t!("page-play-queue", count = self.queue.len()),
+                HomeMessage::PlayQueue,
+                false,
+            )
+        })
         .spacing(16.0)
         .width(Length::Fill);
         let collections = scrollable(collections)
@@ -436,13 +862,20 @@ impl Home {
         let bottom = column!(
             icon_button(
                 icons::COMMENT,
-                "Comments",
+                t!("page-comments"),
                 HomeMessage::Goto(Page::goto_comments()),
                 self.current_page()
                     .map(Page::is_comments)
                     .unwrap_or_default()
             ),
-            icon_button(icons::SETTINGS, "Settings", HomeMessage::Settings, false)
+            icon_button(
+                icons::SETTINGS,
+                t!("page-settings"),
+                HomeMessage::Goto(Page::goto_settings()),
+                self.current_page()
+                    .map(Page::is_settings)
+                    .unwrap_or_default()
+            )
         )
         .spacing(16.0);
 
@@ -459,7 +892,7 @@ impl Home {
 
     fn inner(&self) -> Element<'_, HomeMessage> {
         match self.current_page() {
-            None => center(text("Home Page"))
+            None => center(text(t!("page-home-title")))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into(),
@@ -491,7 +924,7 @@ impl Home {
     }
 
     fn filters_view(&self) -> Element<'_, HomeMessage> {
-        let size = typo::H7;
+        let size = typo::h7();
         let padding = Padding::new(2.0).left(5.0).right(5.0);
 
         let vertical_rule = || container(vertical_rule(2.0)).height(20.0);
@@ -524,7 +957,7 @@ impl Home {
         };
 
         let progress = {
-            let text = text("Progress:").size(size);
+            let text = text(t!("filter-progress")).size(size);
             let progress = pick_list(
                 ProgressKind::ALL,
                 Some(self.filters.progress.kind),
@@ -546,7 +979,7 @@ impl Home {
         };
 
         let rating = {
-            let text = text("Rating:").size(size);
+            let text = text(t!("filter-rating")).size(size);
             let rating = pick_list(
                 RatingKind::ALL,
                 Some(self.filters.rating.kind),
@@ -565,7 +998,7 @@ impl Home {
         };
 
         let comments = {
-            let text = text("Comments:").size(size);
+            let text = text(t!("filter-comments")).size(size);
             let icon = self
                 .filters
                 .comments
@@ -590,7 +1023,7 @@ impl Home {
         };
 
         let release = {
-            let text = text("Release:").size(size);
+            let text = text(t!("filter-release")).size(size);
             let icon = self
                 .filters
                 .release
@@ -615,9 +1048,9 @@ impl Home {
         };
 
         let duration = {
-            let hr = text("hrs").size(size);
-            let min = text("mins").size(size);
-            let text = text("Duration:").size(size);
+            let hr = text(t!("filter-hours")).size(size);
+            let min = text(t!("filter-minutes")).size(size);
+            let text = text(t!("filter-duration")).size(size);
             let icon = self
                 .filters
                 .duration
@@ -658,7 +1091,7 @@ impl Home {
 
         let mode = {
             let mode = text(self.filters.mode.to_string()).size(size);
-            let text = text("Combination mode:").size(size);
+            let text = text(t!("filter-mode")).size(size);
 
             let button = button(mode)
                 .style(button::background)
@@ -668,7 +1101,7 @@ impl Home {
             row!(text, button).spacing(5.0).align_y(Vertical::Center)
         };
 
-        let clear = button(text("Clear filters").size(size))
+        let clear = button(text(t!("filter-clear")).size(size))
             .padding(padding)
             .style(button::text)
             .on_press(HomeMessage::Filter(FilterMessage::Clear));
@@ -692,13 +1125,13 @@ impl Home {
         .align_y(Vertical::Center)
         .wrap();
 
-        let content = column!(text("Filters").size(size), content).spacing(5.0);
+        let content = column!(text(t!("filter-title")).size(size), content).spacing(5.0);
 
         content.into()
     }
 
     fn toolbar(&self) -> Element<'_, HomeMessage> {
-        let size = typo::P;
+        let size = typo::p();
 
         let filter = {
             let icon = if self.show_filters {
@@ -752,7 +1185,7 @@ impl Home {
             empty()
         } else {
             row!(
-                text("Sort by: ").size(H7),
+                text(t!("sort-by")).size(h7()),
                 row(SortKind::ALL.iter().map(|sort| {
                     let order = self.sort.kinds.iter().position(|selected| sort == selected);
                     sort.view(order)
@@ -798,11 +1231,14 @@ impl Home {
     }
 
     fn content_area(&self) -> Element<'_, HomeMessage> {
-        let title = self.current_page().map(Page::name).unwrap_or("Home");
-        let title = container(text(title).size(H6)).max_width(400.0);
+        let title = self
+            .current_page()
+            .map(Page::name)
+            .unwrap_or_else(|| t!("page-home"));
+        let title = container(text(title).size(h6())).max_width(400.0);
 
         let search = {
-            let size = H7;
+            let size = h7();
             let icon = text_input::Icon {
                 font: icons::FONT,
                 code_point: icons::SEARCH,
@@ -811,7 +1247,7 @@ impl Home {
                 spacing: 5.0,
             };
 
-            text_input("Search", &self.search)
+            text_input(&t!("search-placeholder"), &self.search)
                 .icon(icon)
                 .size(size)
                 .width(175.0)
@@ -828,7 +1264,7 @@ impl Home {
             )
             .padding(Padding::ZERO.right(5))
             .align_y(Vertical::Center)
-            .height(H2 * 1.50)
+            .height(h2() * 1.50)
             .width(Length::Fill),
         )
         .style(container_style);
@@ -852,6 +1288,10 @@ impl Home {
     }
 
     pub fn view(&self) -> Element<'_, HomeMessage> {
+        if let Some(player) = self.player.as_ref() {
+            return player.view().map(HomeMessage::Player);
+        }
+
         let content = row!(self.side(), self.content_area())
             .width(Length::Fill)
             .height(Length::Fill)
@@ -860,22 +1300,36 @@ impl Home {
         content.into()
     }
 
+    pub fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.settings.appearance.ui_scale
+    }
+
     pub fn subscription(&self) -> Subscription<HomeMessage> {
+        if let Some(player) = self.player.as_ref() {
+            return player.subscriptions().map(HomeMessage::Player);
+        }
+
         let page = self
             .current_page()
             .map(|page| page.subscription())
             .unwrap_or(Subscription::none());
 
-        let keys = keyboard::on_key_press(|key, modifiers| match key {
-            keyboard::Key::Named(keyboard::key::Named::ArrowLeft) if modifiers.alt() => {
-                Some(HomeMessage::Back)
-            }
-            keyboard::Key::Named(keyboard::key::Named::ArrowRight) if modifiers.alt() => {
-                Some(HomeMessage::Forward)
-            }
-
-            _ => None,
-        });
+        // Key presses belong to the page while it records a binding.
+        let keys = if self
+            .current_page()
+            .map(Page::captures_keys)
+            .unwrap_or_default()
+        {
+            Subscription::none()
+        } else {
+            keyboard::on_key_press(|key, modifiers| {
+                Chord::from_key(&key, modifiers).map(HomeMessage::Chord)
+            })
+        };
 
         Subscription::batch([page, keys])
     }
@@ -883,11 +1337,11 @@ impl Home {
 
 fn icon_button<'a>(
     unicode: char,
-    value: &'a str,
+    value: impl text::IntoFragment<'a>,
     message: HomeMessage,
     current: bool,
 ) -> Element<'a, HomeMessage> {
-    let size = H6;
+    let size = h6();
     let icon = icons::icon(unicode).size(size);
     let text = text(value).size(size);
 
@@ -898,18 +1352,11 @@ fn icon_button<'a>(
             .spacing(16.0),
     )
     .style(move |theme, status| {
-        use button::{Status, Style, background};
+        use button::{Status, background};
         let default = background(theme, status);
 
         match status {
-            Status::Active if current => {
-                let background = theme.extended_palette().background.weakest;
-                Style {
-                    background: Some(background.color.into()),
-                    text_color: background.text,
-                    ..default
-                }
-            }
+            Status::Active if current => theme::selected(theme, default),
             _ => default,
         }
     })
@@ -1,22 +1,25 @@
 // #![allow(dead_code)]
 use super::PageUpdate;
+use crate::config::{Config, Library, VideoConfig};
+use crate::t;
+use crate::theme;
 use crate::utils::filter::*;
 use crate::utils::icons::*;
 use crate::utils::typo::*;
-use crate::utils::{Sort, SortKind, ViewType, empty};
-use crate::video::{Video, VideoId};
+use crate::utils::{self, Sort, SortKind, ViewType, empty};
+use crate::video::{Loudness, Palette, Video, VideoId};
 use iced::{
-    Color, ContentFit, Element, Length, Shadow, Subscription, Task,
+    Background, Color, ContentFit, Degrees, Element, Length, Shadow, Subscription, Task,
     alignment::{Horizontal, Vertical},
     animation::{Animation, Easing},
     mouse,
     time::Instant,
     widget::{
         bottom_center, button, center_x, column, container, float, grid, horizontal_space, image,
-        mouse_area, row, scrollable, stack, text, vertical_space,
+        mouse_area, progress_bar, row, scrollable, stack, text, vertical_space,
     },
 };
-use std::{collections::HashMap, ops::Deref};
+use std::{collections::HashMap, ops::Deref, path::Path};
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum View {
@@ -41,10 +44,10 @@ impl std::fmt::Display for View {
             f,
             "{}",
             match self {
-                Self::Info => "Overview",
-                Self::Comments => "Comments",
-                Self::Data => "Data",
-                Self::Collections => "Collections",
+                Self::Info => t!("view-overview"),
+                Self::Comments => t!("view-comments"),
+                Self::Data => t!("view-data"),
+                Self::Collections => t!("view-collections"),
             }
         )
     }
@@ -59,6 +62,7 @@ pub struct Thumbnail {
 }
 
 impl Thumbnail {
+    /// The sizes at the default text scale, which they grow with.
     const CARD_WIDTH: f32 = 275.0;
     const CARD_HEIGHT: f32 = 275.0;
     const LIST_HEIGHT: f32 = 160.0;
@@ -87,18 +91,18 @@ impl Thumbnail {
                 .height(Length::Fill)
                 .content_fit(ContentFit::Cover)
                 .into(),
-            None => container(empty()).style(container::dark).into(),
+            None => container(empty()).style(theme::card).into(),
         }
     }
 
     fn duration(&self) -> text::Text<'_, iced::Theme, iced::Renderer> {
-        text(self.duration_full()).size(H7)
+        text(self.duration_full()).size(h7())
     }
 
     fn ratings<'a, Message: 'a>(&self) -> Element<'a, Message> {
         let unstars = (5 - self.video.rating).clamp(0, 5);
-        let stars = (0..self.video.rating).map(|_| Element::from(icon(STAR).size(H7)));
-        let unstars = (0..unstars).map(|_| Element::from(icon(UNSTAR).size(H7)));
+        let stars = (0..self.video.rating).map(|_| Element::from(icon(STAR).size(h7())));
+        let unstars = (0..unstars).map(|_| Element::from(icon(UNSTAR).size(h7())));
         let ratings = row(stars.chain(unstars))
             .spacing(2.0)
             .align_y(Vertical::Center);
@@ -106,8 +110,10 @@ impl Thumbnail {
         ratings.into()
     }
 
-    fn progress(&self) -> Element<'_, MoviesMessage> {
+    /// `completion` is the progress from which the video counts as watched.
+    fn progress(&self, completion: f32) -> Element<'_, MoviesMessage> {
         let progress = match self.video.progress {
+            x if x >= completion => PROGRESS_100,
             ..0.15 => PROGRESS_10,
             0.15..0.3 => PROGRESS_20,
             0.3..0.5 => PROGRESS_40,
@@ -117,9 +123,9 @@ impl Thumbnail {
             _ => PROGRESS_100,
         };
 
-        let text = text(format!("{}%", self.video.progress * 100.0)).size(H7);
+        let text = text(format!("{}%", self.video.progress * 100.0)).size(h7());
 
-        let icon = icon(progress).size(H4);
+        let icon = icon(progress).size(h4());
 
         row!(icon, text)
             .spacing(3.0)
@@ -127,27 +133,32 @@ impl Thumbnail {
             .into()
     }
 
-    pub fn list(&self, now: Instant) -> Element<'_, MoviesMessage> {
-        let title = text(&self.video.name).size(H6);
+    pub fn list(&self, now: Instant, completion: f32) -> Element<'_, MoviesMessage> {
+        let title = text(&self.video.name).size(h6());
 
         let ratings = self.ratings();
 
-        let add = mouse_area(icon(BOOKMARK).size(H3))
+        let add = mouse_area(icon(BOOKMARK).size(h3()))
             .interaction(mouse::Interaction::Pointer)
             .on_press(MoviesMessage::AddCollection(self.video.id));
 
-        let synapsis = text(&self.synapsis).size(H7).height(52.0);
+        let synapsis = text(&self.synapsis).size(h7()).height(52.0);
 
-        let bottom = row!(self.progress(), self.duration(), horizontal_space(), add)
-            .spacing(24)
-            .align_y(Vertical::Center)
-            .width(Length::Fill);
+        let bottom = row!(
+            self.progress(completion),
+            self.duration(),
+            horizontal_space(),
+            add
+        )
+        .spacing(24)
+        .align_y(Vertical::Center)
+        .width(Length::Fill);
 
         let details = column!(title, ratings, synapsis,vertical_space(), bottom).spacing(8);
 
         let details = mouse_area(
             container(details)
-                .style(container::dark)
+                .style(theme::card)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .padding([5, 10]),
@@ -155,10 +166,10 @@ impl Thumbnail {
         .interaction(mouse::Interaction::Pointer)
         .on_press(MoviesMessage::Details(self.video.id));
 
-        let img = container(self.image()).width(Self::LIST_WIDTH);
+        let img = container(self.image()).width(Self::LIST_WIDTH * scale());
 
         let overlay = {
-            let size = H2;
+            let size = h2();
             let play = mouse_area(
                 icon(PLAY)
                     .size(size)
@@ -179,7 +190,7 @@ impl Thumbnail {
         let content = mouse_area(
             row!(img, details)
                 .align_y(Vertical::Center)
-                .height(Self::LIST_HEIGHT),
+                .height(Self::LIST_HEIGHT * scale()),
         )
         .on_exit(MoviesMessage::Hovered(self.video.id, false))
         .on_enter(MoviesMessage::Hovered(self.video.id, true));
@@ -202,12 +213,12 @@ impl Thumbnail {
         content.into()
     }
 
-    pub fn card(&self, now: Instant) -> Element<'_, MoviesMessage> {
+    pub fn card(&self, now: Instant, completion: f32) -> Element<'_, MoviesMessage> {
         let padding = [3, 6];
 
         let top = {
-            let progress = self.progress();
-            let add = mouse_area(icon(BOOKMARK).size(H4))
+            let progress = self.progress(completion);
+            let add = mouse_area(icon(BOOKMARK).size(h4()))
                 .interaction(mouse::Interaction::Pointer)
                 .on_press(MoviesMessage::AddCollection(self.video.id));
 
@@ -220,7 +231,7 @@ impl Thumbnail {
         };
 
         let details = {
-            let title = text(&self.video.name).size(H7);
+            let title = text(&self.video.name).size(h7());
             let ratings = self.ratings();
 
             let details = row!(ratings, horizontal_space(), self.duration())
@@ -230,14 +241,14 @@ impl Thumbnail {
             mouse_area(
                 container(column!(title, details).width(Length::Fill).spacing(10.0))
                     .padding(padding)
-                    .style(container::dark),
+                    .style(theme::card),
             )
             .interaction(mouse::Interaction::Pointer)
             .on_press(MoviesMessage::Details(self.video.id))
         };
 
         let play = {
-            let size = H2 * 1.75;
+            let size = h2() * 1.75;
 
             let play = mouse_area(
                 icon(PLAY)
@@ -260,7 +271,7 @@ impl Thumbnail {
 
         let img: Element<'_, MoviesMessage> = self.image();
 
-        let content = stack![img, overlay].width(Thumbnail::CARD_WIDTH);
+        let content = stack![img, overlay].width(Thumbnail::CARD_WIDTH * scale());
 
         let content = column!(content, details);
 
@@ -316,6 +327,46 @@ fn sort(x: &Video, y: &Video, sorts: &[SortKind]) -> std::cmp::Ordering {
     std::cmp::Ordering::Equal
 }
 
+/// A gradient from the dominant colour of the artwork into `base`.
+fn backdrop(base: Color, palette: Palette) -> Background {
+    let dominant = mix(palette.dominant(), base, 0.45);
+
+    let gradient = iced::gradient::Linear::new(Degrees(90.0))
+        .add_stop(0.0, dominant.scale_alpha(0.9))
+        .add_stop(1.0, base.scale_alpha(0.75));
+
+    Background::Gradient(gradient.into())
+}
+
+/// Fills `style` with `color`, picking dark or light text to stand out on it.
+fn tinted(style: button::Style, color: Color, status: button::Status) -> button::Style {
+    let color = match status {
+        button::Status::Hovered => mix(color, Color::WHITE, 0.15),
+        button::Status::Pressed => mix(color, Color::BLACK, 0.15),
+        _ => color,
+    };
+    let luminance = 0.299 * color.r + 0.587 * color.g + 0.114 * color.b;
+
+    button::Style {
+        background: Some(color.into()),
+        text_color: if luminance > 0.6 {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        },
+        ..style
+    }
+}
+
+/// `a` moved towards `b` by `amount`, from 0 to 1.
+fn mix(a: Color, b: Color, amount: f32) -> Color {
+    Color::from_rgb(
+        a.r + (b.r - a.r) * amount,
+        a.g + (b.g - a.g) * amount,
+        a.b + (b.b - a.b) * amount,
+    )
+}
+
 fn filter(video: &Video, filter: Filter) -> bool {
     let progress = filter.progress.compare(video.progress);
     let rating = filter.rating.compare(video.rating);
@@ -356,6 +407,8 @@ impl Preview {
         thumbnail: &'a Thumbnail,
         on_play: impl Fn(VideoId) -> Message,
         on_view: impl Fn(View) -> Message,
+        on_queue: impl Fn(QueueAction) -> Message,
+        on_bookmark: impl Fn(VideoId, f64) -> Message,
     ) -> Element<'a, Message>
     where
         Message: 'a + Clone,
@@ -372,18 +425,18 @@ impl Preview {
                 None => container(empty())
                     .height(img_height)
                     .width(img_height * ratio)
-                    .style(container::dark)
+                    .style(theme::card)
                     .into(),
             }
         };
 
         let header = {
-            let separator = || Element::from(text("•").size(H3));
+            let separator = || Element::from(text("•").size(h3()));
 
-            let title = text(&thumbnail.name).size(H4);
+            let title = text(&thumbnail.name).size(h4());
             let duration = thumbnail.duration();
             let rating = thumbnail.ratings();
-            let release = text(thumbnail.release).size(H7);
+            let release = text(thumbnail.release).size(h7());
 
             let details = row!(release, separator(), duration)
                 .spacing(6)
@@ -393,7 +446,7 @@ impl Preview {
             let tag_len = thumbnail.tags.len();
 
             for (i, tag) in thumbnail.tags.iter().enumerate() {
-                tags.push(Element::from(text(tag).size(H7)));
+                tags.push(Element::from(text(tag).size(h7())));
 
                 if i < tag_len - 1 {
                     tags.push(separator())
@@ -408,7 +461,7 @@ impl Preview {
             let is_selected = self.view == view;
 
             Element::from(
-                button(text(view.to_string()).size(H7))
+                button(text(view.to_string()).size(h7()))
                     .padding([3, 6])
                     .on_press((on_view)(view))
                     .style(move |theme, status| {
@@ -418,14 +471,7 @@ impl Preview {
                         let default = Style { border, ..default };
 
                         match status {
-                            Status::Active if is_selected => {
-                                let background = theme.extended_palette().background.neutral;
-                                Style {
-                                    background: Some(background.color.into()),
-                                    text_color: background.text,
-                                    ..default
-                                }
-                            }
+                            Status::Active if is_selected => theme::selected(theme, default),
                             _ => default,
                         }
                     }),
@@ -463,7 +509,7 @@ impl Preview {
                         value: impl text::IntoFragment<'a>,
                         unicode: char,
                     ) -> Element<'a, Message> {
-                        let size = H7;
+                        let size = h7();
                         let value = text(value).size(size);
                         let value = row!(icon(unicode).size(size), value)
                             .spacing(2.0)
@@ -475,25 +521,29 @@ impl Preview {
                             .into()
                     }
 
-                    let duration = data("Duration", thumbnail.duration_short(), CLOCK);
+                    let duration = data(t!("details-duration"), thumbnail.duration_short(), CLOCK);
 
-                    let rating = data("Rating", format!("{}/5", thumbnail.rating), STAR);
+                    let rating = data(
+                        t!("details-rating"),
+                        format!("{}/5", thumbnail.rating),
+                        STAR,
+                    );
 
-                    let comments = data("Comments", thumbnail.comments, NUMBER);
+                    let comments = data(t!("details-comments"), thumbnail.comments, NUMBER);
 
-                    let release = data("Release Date", thumbnail.release_short(), CALENDAR);
+                    let release = data(t!("details-release"), thumbnail.release_short(), CALENDAR);
 
-                    let added = data("Date Added", thumbnail.added_short(), CALENDAR);
+                    let added = data(t!("details-added"), thumbnail.added_short(), CALENDAR);
 
-                    let count = data("Watch Count", thumbnail.watch_count, EYE);
+                    let count = data(t!("details-watch-count"), thumbnail.watch_count, EYE);
 
                     let progress = data(
-                        "Watch Progress",
+                        t!("details-watch-progress"),
                         format!("{}%", thumbnail.progress * 100.0),
                         HOURGLASS,
                     );
 
-                    let recent = data("Recent Watch", thumbnail.release_short(), CALENDAR);
+                    let recent = data(t!("details-recent"), thumbnail.release_short(), CALENDAR);
 
                     let r1 = row!(
                         duration,
@@ -519,7 +569,60 @@ impl Preview {
                     .align_y(Vertical::Center)
                     .width(Length::Fill);
 
-                    let content = column!(r1, r2).spacing(30.0);
+                    let bookmarks = thumbnail.bookmarks.iter().map(|bookmark| {
+                        let position = bookmark.position as u64;
+                        let position = format!(
+                            "{:02}:{:02}:{:02}",
+                            position / 3600,
+                            position % 3600 / 60,
+                            position % 60
+                        );
+
+                        Element::from(
+                            button(
+                                row!(
+                                    icon(PLAY).size(h7()),
+                                    text(&bookmark.name).size(h7()).width(Length::Fill),
+                                    text(position).size(h7())
+                                )
+                                .spacing(8.0)
+                                .align_y(Vertical::Center),
+                            )
+                            .width(Length::Fill)
+                            .padding([3, 6])
+                            .style(button::text)
+                            .on_press((on_bookmark)(self.id, bookmark.position)),
+                        )
+                    });
+
+                    let (loudness, peak) = match thumbnail.loudness {
+                        Some(loudness) => (
+                            format!("{:.1} LUFS", loudness.integrated),
+                            format!("{:.1} dBTP", loudness.true_peak),
+                        ),
+                        None => (t!("common-unknown"), t!("common-unknown")),
+                    };
+
+                    let r3 = row!(
+                        data(t!("details-loudness"), loudness, VOLUME),
+                        horizontal_space(),
+                        data(t!("details-true-peak"), peak, VOLUME),
+                        horizontal_space(),
+                    )
+                    .align_y(Vertical::Center)
+                    .width(Length::Fill);
+
+                    let mut content = column!(r1, r2, r3).spacing(30.0);
+
+                    if !thumbnail.bookmarks.is_empty() {
+                        content = content.push(
+                            column!(
+                                text(t!("details-bookmarks")).size(h7()),
+                                scrollable(column(bookmarks).spacing(2.0)).height(90.0)
+                            )
+                            .spacing(4.0),
+                        );
+                    }
 
                     content.width(width).into()
                 }
@@ -539,25 +642,90 @@ impl Preview {
             }
         };
 
-        let play = center_x(
-            button(
-                row!(icon(PLAY).size(H5), text("Play").size(H5))
-                    .spacing(16.0)
-                    .align_y(Vertical::Center),
+        // Colours picked from the artwork, once known.
+        let palette = thumbnail.palette;
+
+        let action = |label: String, message: Message| {
+            button(text(label).size(h7()))
+                .padding([6, 12])
+                .on_press(message)
+                .style(move |theme, status| {
+                    let default = button::text(theme, status);
+                    let border = default.border.rounded(5);
+                    let text_color = match palette {
+                        Some(palette) => palette.vibrant(),
+                        None => default.text_color,
+                    };
+
+                    button::Style {
+                        border,
+                        text_color,
+                        ..default
+                    }
+                })
+        };
+
+        let mut actions = vec![
+            Element::from(
+                button(
+                    row!(icon(PLAY).size(h5()), text(t!("preview-play")).size(h5()))
+                        .spacing(16.0)
+                        .align_y(Vertical::Center),
+                )
+                .padding([6, 12])
+                .on_press((on_play)(self.id))
+                .style(move |theme, status| {
+                    let default = button::background(theme, status);
+                    let border = default.border.rounded(5);
+                    let default = button::Style { border, ..default };
+
+                    match palette {
+                        Some(palette) => tinted(default, palette.vibrant(), status),
+                        None => default,
+                    }
+                }),
+            ),
+            action(
+                t!("preview-play-next"),
+                (on_queue)(QueueAction::PlayNext(self.id)),
             )
-            .padding([6, 12])
-            .on_press((on_play)(self.id))
-            .style(|theme, status| {
-                let default = button::background(theme, status);
-                let border = default.border.rounded(5);
-
-                button::Style { border, ..default }
-            }),
-        );
+            .into(),
+            action(
+                t!("preview-add-to-queue"),
+                (on_queue)(QueueAction::Add(self.id)),
+            )
+            .into(),
+        ];
+
+        if thumbnail.series.is_some() {
+            actions.push(
+                action(
+                    t!("preview-play-season"),
+                    (on_queue)(QueueAction::PlaySeason(self.id)),
+                )
+                .into(),
+            );
+        }
+
+        let play = center_x(row(actions).spacing(8.0).align_y(Vertical::Center));
+
+        let progress = progress_bar(0.0..=1.0, thumbnail.progress)
+            .girth(4.0)
+            .style(move |theme| {
+                let default = progress_bar::primary(theme);
+
+                match palette {
+                    Some(palette) => progress_bar::Style {
+                        bar: palette.vibrant().into(),
+                        ..default
+                    },
+                    None => default,
+                }
+            });
 
         let tabs = column!(tabs, view).height(Length::Fill).spacing(16.0);
 
-        let content = column!(header, tabs).spacing(24.0).width(675.0);
+        let content = column!(header, progress, tabs).spacing(24.0).width(675.0);
 
         let content = row!(img, content).spacing(20.0);
 
@@ -566,11 +734,17 @@ impl Preview {
             .max_height(465.0)
             .align_x(Horizontal::Center)
             .width(Length::Fill)
-            .style(|theme| {
-                let default = container::dark(theme);
-                let background = default
-                    .background
-                    .map(|background| background.scale_alpha(0.75));
+            .style(move |theme| {
+                let default = theme::card(theme);
+                let background = match palette {
+                    Some(palette) => {
+                        let base = theme.extended_palette().background.weak.color;
+                        Some(backdrop(base, palette))
+                    }
+                    None => default
+                        .background
+                        .map(|background| background.scale_alpha(0.75)),
+                };
 
                 let shadow = default.shadow;
                 let shadow = Shadow {
@@ -593,11 +767,14 @@ impl Preview {
         thumbnail: &'a Thumbnail,
         on_play: impl Fn(VideoId) -> Message,
         on_view: impl Fn(View) -> Message,
+        on_queue: impl Fn(QueueAction) -> Message,
+        on_bookmark: impl Fn(VideoId, f64) -> Message,
     ) -> Element<'a, Message>
     where
         Message: 'a + Clone,
     {
-        let overlay = bottom_center(self.overlay(thumbnail, on_play, on_view));
+        let overlay =
+            bottom_center(self.overlay(thumbnail, on_play, on_view, on_queue, on_bookmark));
 
         let img: Element<'_, Message> = match &thumbnail.backdrop {
             Some(handle) => image(handle)
@@ -608,11 +785,11 @@ impl Preview {
             None => container(empty())
                 .width(Length::Fill)
                 .height(Length::FillPortion(3))
-                .style(container::dark)
+                .style(theme::card)
                 .into(),
         };
 
-        let content = container(column!(img,)).style(container::dark);
+        let content = container(column!(img,)).style(theme::card);
 
         let content = stack![content, overlay];
 
@@ -620,11 +797,24 @@ impl Preview {
     }
 }
 
+/// Ad-hoc changes to the play queue, carried out by [`Home`](super::Home).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueueAction {
+    PlayNext(VideoId),
+    Add(VideoId),
+    PlaySeason(VideoId),
+}
+
 #[derive(Debug, Clone)]
 pub enum MoviesMessage {
     Hovered(VideoId, bool),
     Thumbnails(Vec<Thumbnail>),
     Play(VideoId),
+    /// Plays a video from a bookmarked position, in seconds.
+    PlayFrom(VideoId, f64),
+    Queue(QueueAction),
+    /// The colours of the artwork of a video, `None` when it could not be read.
+    Palette(VideoId, Option<Palette>),
     AddCollection(VideoId),
     Details(VideoId),
     View(View),
@@ -642,25 +832,54 @@ pub struct Movies {
     filter: Filter,
     preview: Option<Preview>,
     preview_back: Option<Preview>,
+    /// The progress from which a video counts as watched.
+    completion: f32,
 }
 
 impl Movies {
-    pub fn boot(sort: Sort, filters: Filter, grid: bool) -> (Self, Task<MoviesMessage>) {
-        let load_thumbnails = Task::perform(
-            async {
-                let alt = (6..12).map(Video::testing2);
-                (0..6).map(Video::testing).chain(alt).collect::<Vec<_>>()
-            },
-            |videos| MoviesMessage::Thumbnails(videos.into_iter().map(Thumbnail::new).collect()),
-        );
+    pub fn boot(
+        sort: Sort,
+        filters: Filter,
+        grid: bool,
+        settings: &Config,
+    ) -> (Self, Task<MoviesMessage>) {
+        let load_thumbnails = Self::scan(settings.library.clone()).map(MoviesMessage::Thumbnails);
 
         (
-            Self::new(sort, grid, filters),
+            Self::new(sort, grid, filters, settings.playback.completion),
             Task::batch([load_thumbnails]),
         )
     }
 
-    fn new(sort: Sort, grid: bool, filter: Filter) -> Self {
+    /// Finds the videos of `library`, along with what is stored about them.
+    pub fn scan(library: Library) -> Task<Vec<Thumbnail>> {
+        Task::perform(
+            async move {
+                let alt = (6..12).map(Video::testing2);
+                let scanned = utils::library::scan(&library)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, path)| Video::from_path(12 + index, path));
+
+                (0..6)
+                    .map(Video::testing)
+                    .chain(alt)
+                    .chain(scanned)
+                    .map(|mut video| {
+                        let config = VideoConfig::load(&video.path);
+                        video.bookmarks = config.bookmarks;
+                        video.credits = config.credits;
+                        video.loudness = config.loudness;
+                        video.palette = config.palette;
+                        video
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |videos| videos.into_iter().map(Thumbnail::new).collect(),
+        )
+    }
+
+    fn new(sort: Sort, grid: bool, filter: Filter, completion: f32) -> Self {
         let now = Instant::now();
         Self {
             now,
@@ -671,6 +890,7 @@ impl Movies {
             filter,
             preview: None,
             preview_back: None,
+            completion,
         }
     }
 
@@ -689,8 +909,8 @@ impl Movies {
                 self.focused = Some(id);
                 Task::none()
             }
-            MoviesMessage::Play(id) => {
-                println!("Play {id:?} pressed");
+            // Playback is started by `Home`, which owns the player.
+            MoviesMessage::Play(_) | MoviesMessage::PlayFrom(..) | MoviesMessage::Queue(_) => {
                 Task::none()
             }
             MoviesMessage::Details(id) => {
@@ -700,7 +920,7 @@ impl Movies {
                 });
                 self.preview_back = None;
                 self.focused = None;
-                Task::none()
+                self.pick_palette(id)
             }
             MoviesMessage::AddCollection(id) => {
                 println!("Add {id:?} to collection pressed");
@@ -710,7 +930,24 @@ impl Movies {
                 for thumbnail in thumbnails {
                     self.thumbnails.insert(thumbnail.video.id, thumbnail);
                 }
+                Task::none()
+            }
+            MoviesMessage::Palette(_, None) => Task::none(),
+            MoviesMessage::Palette(id, Some(palette)) => {
+                let Some(path) = self.video(id).map(|video| video.path.clone()) else {
+                    return Task::none();
+                };
+
+                let result = VideoConfig::update(&path, |config| config.palette = Some(palette));
+                if let Err(err) = result {
+                    eprintln!("Error saving video settings: \n{err}");
+                }
 
+                for thumbnail in self.thumbnails.values_mut() {
+                    if thumbnail.video.path == path {
+                        thumbnail.video.palette = Some(palette);
+                    }
+                }
                 Task::none()
             }
             MoviesMessage::View(view) => {
@@ -722,6 +959,28 @@ impl Movies {
         }
     }
 
+    /// Picks the colours of the artwork of the video, unless they are known.
+    fn pick_palette(&self, id: VideoId) -> Task<MoviesMessage> {
+        let Some(video) = self.video(id).filter(|video| video.palette.is_none()) else {
+            return Task::none();
+        };
+        let Some(artwork) = video.poster.clone().or_else(|| video.backdrop.clone()) else {
+            return Task::none();
+        };
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || utils::palette::extract(artwork)),
+            move |res| match res {
+                Ok(Ok(palette)) => MoviesMessage::Palette(id, Some(palette)),
+                Ok(Err(err)) => {
+                    eprintln!("Error picking artwork colours: \n{err}");
+                    MoviesMessage::Palette(id, None)
+                }
+                Err(_) => MoviesMessage::Palette(id, None),
+            },
+        )
+    }
+
     pub fn page_update(&mut self, update: PageUpdate, now: Instant) {
         self.now = now;
 
@@ -729,17 +988,18 @@ impl Movies {
             PageUpdate::Sort(sort) => self.sort = sort,
             PageUpdate::Layout(kind) => self.grid = matches!(kind, ViewType::Grid),
             PageUpdate::Filters(filters) => self.filter = filters,
+            PageUpdate::Completion(completion) => self.completion = completion,
         }
     }
 
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> String {
         self.preview
             .and_then(|preview| {
                 self.thumbnails
                     .get(&preview.id)
-                    .map(|thumbnail| thumbnail.name.as_str())
+                    .map(|thumbnail| thumbnail.name.clone())
             })
-            .unwrap_or("Movies")
+            .unwrap_or_else(|| t!("page-movies"))
     }
 
     pub fn can_back(&self) -> bool {
@@ -754,6 +1014,65 @@ impl Movies {
         self.preview.is_none()
     }
 
+    /// The videos currently shown, in their filtered and sorted order.
+    pub fn videos(&self) -> impl Iterator<Item = &Video> {
+        self.thumbnails().map(|thumbnail| &thumbnail.video)
+    }
+
+    pub fn video(&self, id: VideoId) -> Option<&Video> {
+        self.thumbnails.get(&id).map(|thumbnail| &thumbnail.video)
+    }
+
+    /// Changes the record of a video, refreshing its artwork.
+    pub fn edit_video(&mut self, id: VideoId, edit: impl FnOnce(&mut Video)) {
+        if let Some(thumbnail) = self.thumbnails.get_mut(&id) {
+            let mut video = thumbnail.video.clone();
+            edit(&mut video);
+            *thumbnail = Thumbnail::new(video);
+        }
+    }
+
+    /// Changes the record of a video, keeping its artwork and animations.
+    pub fn update_video(&mut self, id: VideoId, edit: impl FnOnce(&mut Video)) {
+        if let Some(thumbnail) = self.thumbnails.get_mut(&id) {
+            edit(&mut thumbnail.video);
+        }
+    }
+
+    /// Replaces the videos with those of a new scan of the library.
+    pub fn set_thumbnails(&mut self, thumbnails: Vec<Thumbnail>) {
+        let previous = std::mem::replace(
+            &mut self.thumbnails,
+            thumbnails
+                .into_iter()
+                .map(|thumbnail| (thumbnail.video.id, thumbnail))
+                .collect(),
+        );
+
+        // Ids follow the order of the scan, so they may now be another video.
+        let unchanged = |id: VideoId| match (previous.get(&id), self.thumbnails.get(&id)) {
+            (Some(old), Some(new)) => old.video.path == new.video.path,
+            _ => false,
+        };
+        self.preview = self.preview.filter(|preview| unchanged(preview.id));
+        self.preview_back = self.preview_back.filter(|preview| unchanged(preview.id));
+        self.focused = self.focused.filter(|id| unchanged(*id));
+    }
+
+    /// Stores the measured loudness of every entry of the file at `path`.
+    pub fn set_loudness(&mut self, path: &Path, loudness: Loudness) {
+        for thumbnail in self.thumbnails.values_mut() {
+            if thumbnail.video.path == path {
+                thumbnail.video.loudness = Some(loudness);
+            }
+        }
+    }
+
+    /// Every video of the collection, ignoring filters.
+    pub fn all(&self) -> impl Iterator<Item = &Video> {
+        self.thumbnails.values().map(|thumbnail| &thumbnail.video)
+    }
+
     fn thumbnails(&self) -> impl Iterator<Item = &Thumbnail> {
         let mut temp = self
             .thumbnails
@@ -771,11 +1090,13 @@ impl Movies {
     }
 
     fn grid(&self) -> Element<'_, MoviesMessage> {
-        let content = self.thumbnails().map(|thumbnail| thumbnail.card(self.now));
+        let content = self
+            .thumbnails()
+            .map(|thumbnail| thumbnail.card(self.now, self.completion));
 
         let content = grid(content)
             .spacing(16)
-            .fluid(Thumbnail::CARD_WIDTH)
+            .fluid(Thumbnail::CARD_WIDTH * scale())
             .height(grid::aspect_ratio(
                 Thumbnail::CARD_WIDTH,
                 Thumbnail::CARD_HEIGHT,
@@ -787,7 +1108,9 @@ impl Movies {
     }
 
     fn list(&self) -> Element<'_, MoviesMessage> {
-        let content = self.thumbnails().map(|thumbnail| thumbnail.list(self.now));
+        let content = self
+            .thumbnails()
+            .map(|thumbnail| thumbnail.list(self.now, self.completion));
 
         let content = column(content).spacing(16);
 
@@ -804,7 +1127,13 @@ impl Movies {
                     .get(&preview.id)
                     .expect("Preview Id missing");
 
-                preview.view(thumbnail, MoviesMessage::Play, MoviesMessage::View)
+                preview.view(
+                    thumbnail,
+                    MoviesMessage::Play,
+                    MoviesMessage::View,
+                    MoviesMessage::Queue,
+                    MoviesMessage::PlayFrom,
+                )
             }
             None if self.grid => self.grid(),
             None => self.list(),
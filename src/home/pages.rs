@@ -3,16 +3,23 @@ use iced::{
     time::Instant,
     widget::{center, text},
 };
+use std::path::Path;
+use std::str::FromStr;
 
 use super::HomeMessage;
-use super::movies::{Movies, MoviesMessage};
+use super::movies::{Movies, MoviesMessage, Thumbnail};
+use super::settings::{Settings, SettingsMessage};
+use crate::t;
 use crate::utils::{Filter, Sort, ViewType};
+use crate::video::{Loudness, Video, VideoId};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PageUpdate {
     Layout(ViewType),
     Sort(Sort),
     Filters(Filter),
+    /// The progress from which a video counts as watched.
+    Completion(f32),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -22,6 +29,23 @@ pub enum PageKind {
     Comments,
     Search,
     Custom,
+    Settings,
+}
+
+impl FromStr for PageKind {
+    type Err = String;
+
+    /// Reads the name a page is given on the command line, such as `movies`.
+    /// Only pages which can be opened yet are known.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "movies" => Ok(Self::Movies),
+            "settings" => Ok(Self::Settings),
+            _ => Err(format!(
+                "Unknown page \"{input}\", expected movies or settings"
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +55,7 @@ pub enum Page {
     Comments(()),
     Search(()),
     Custom(()),
+    Settings(Settings),
 }
 
 impl Page {
@@ -46,6 +71,10 @@ impl Page {
         PageKind::Comments
     }
 
+    pub fn goto_settings() -> PageKind {
+        PageKind::Settings
+    }
+
     pub fn is_shows(&self) -> bool {
         matches!(self, Self::Shows(_))
     }
@@ -62,6 +91,19 @@ impl Page {
         matches!(self, Self::Custom(_))
     }
 
+    pub fn is_settings(&self) -> bool {
+        matches!(self, Self::Settings(_))
+    }
+
+    /// Whether the page takes key presses for itself, such as to record a
+    /// keybinding.
+    pub fn captures_keys(&self) -> bool {
+        match self {
+            Self::Settings(settings) => settings.is_recording(),
+            _ => false,
+        }
+    }
+
     pub fn movies_update(&mut self, message: MoviesMessage, now: Instant) -> Task<MoviesMessage> {
         match self {
             Self::Movies(movies) => movies.update(message, now),
@@ -69,9 +111,72 @@ impl Page {
         }
     }
 
-    pub fn name(&self) -> &str {
+    pub fn settings_update(&mut self, message: SettingsMessage) -> Task<SettingsMessage> {
+        match self {
+            Self::Settings(settings) => settings.update(message),
+            _ => Task::none(),
+        }
+    }
+
+    /// The videos currently shown by the page, in display order.
+    pub fn videos(&self) -> Vec<Video> {
+        match self {
+            Self::Movies(movies) => movies.videos().cloned().collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Every video in the page's collection, ignoring filters.
+    pub fn all_videos(&self) -> Vec<Video> {
+        match self {
+            Self::Movies(movies) => movies.all().cloned().collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn video(&self, id: VideoId) -> Option<&Video> {
+        match self {
+            Self::Movies(movies) => movies.video(id),
+            _ => None,
+        }
+    }
+
+    pub fn edit_video(&mut self, id: VideoId, edit: impl FnOnce(&mut Video)) {
+        if let Self::Movies(movies) = self {
+            movies.edit_video(id, edit);
+        }
+    }
+
+    pub fn update_video(&mut self, id: VideoId, edit: impl FnOnce(&mut Video)) {
+        if let Self::Movies(movies) = self {
+            movies.update_video(id, edit);
+        }
+    }
+
+    /// The first video of the collection matching `predicate`.
+    pub fn find_video(&self, predicate: impl FnMut(&&Video) -> bool) -> Option<&Video> {
+        match self {
+            Self::Movies(movies) => movies.all().find(predicate),
+            _ => None,
+        }
+    }
+
+    pub fn set_thumbnails(&mut self, thumbnails: Vec<Thumbnail>) {
+        if let Self::Movies(movies) = self {
+            movies.set_thumbnails(thumbnails);
+        }
+    }
+
+    pub fn set_loudness(&mut self, path: &Path, loudness: Loudness) {
+        if let Self::Movies(movies) = self {
+            movies.set_loudness(path, loudness);
+        }
+    }
+
+    pub fn name(&self) -> String {
         match self {
             Self::Movies(movies) => movies.name(),
+            Self::Settings(_) => t!("page-settings"),
             _ => todo!(),
         }
     }
@@ -79,7 +184,8 @@ impl Page {
     pub fn show_tools(&self) -> bool {
         match self {
             Self::Movies(movies) => movies.show_tools(),
-            _ => todo!()
+            Self::Settings(_) => false,
+            _ => todo!(),
         }
     }
 
@@ -87,6 +193,7 @@ impl Page {
     pub fn can_back(&self) -> bool {
         match self {
             Self::Movies(movies) => movies.can_back(),
+            Self::Settings(_) => false,
             _ => todo!(),
         }
     }
@@ -95,6 +202,7 @@ impl Page {
     pub fn can_forward(&self) -> bool {
         match self {
             Self::Movies(movies) => movies.can_forward(),
+            Self::Settings(_) => false,
             _ => todo!(),
         }
     }
@@ -104,6 +212,7 @@ impl Page {
     pub fn back(&mut self) -> bool {
         match self {
             Self::Movies(movies) => movies.back(),
+            Self::Settings(_) => false,
             _ => todo!(),
         }
     }
@@ -113,6 +222,7 @@ impl Page {
     pub fn forward(&mut self) -> bool {
         match self {
             Self::Movies(movies) => movies.forward(),
+            Self::Settings(_) => false,
             _ => todo!(),
         }
     }
@@ -120,6 +230,7 @@ impl Page {
     pub fn page_update(&mut self, update: PageUpdate, now: Instant) {
         match self {
             Self::Movies(movies) => movies.page_update(update, now),
+            Self::Settings(_) => {}
             _ => todo!(),
         }
     }
@@ -127,29 +238,31 @@ impl Page {
     pub fn subscription(&self) -> Subscription<HomeMessage> {
         match self {
             Self::Movies(movies) => movies.subscription().map(HomeMessage::Movies),
+            Self::Settings(settings) => settings.subscription().map(HomeMessage::Settings),
             _ => todo!(),
         }
     }
 
     pub fn view(&self) -> Element<'_, HomeMessage> {
         match self {
-            Self::Shows(_) => center(text("Shows"))
+            Self::Shows(_) => center(text(t!("page-shows")))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into(),
             Self::Movies(movies) => movies.view().map(HomeMessage::Movies),
-            Self::Comments(_) => center(text("Comments"))
+            Self::Comments(_) => center(text(t!("page-comments")))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into(),
-            Self::Search(_) => center(text("Search"))
+            Self::Search(_) => center(text(t!("page-search")))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into(),
-            Self::Custom(_) => center(text("Custom"))
+            Self::Custom(_) => center(text(t!("page-custom")))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .into(),
+            Self::Settings(settings) => settings.view().map(HomeMessage::Settings),
         }
     }
 }
@@ -0,0 +1,667 @@
+use iced::{
+    Color, Element, Event, Length, Subscription, Task,
+    alignment::Vertical,
+    event, keyboard,
+    widget::{
+        button, column, container, horizontal_space, pick_list, row, scrollable, slider, text,
+    },
+};
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use crate::config::{
+    Appearance, Config, Library, Playback, SubtitleMode, SubtitleStyle, ThemeChoice,
+};
+use crate::i18n::Language;
+use crate::keymap::{Action, Chord, Keymap, Scope};
+use crate::t;
+use crate::theme;
+use crate::utils::ViewType;
+use crate::utils::typo::*;
+
+const CONFLICT_COLOR: Color = Color::from_rgb(0.95, 0.45, 0.35);
+
+#[derive(Debug, Clone)]
+pub enum SettingsMessage {
+    /// Changes the playback settings while a slider is dragged.
+    Playback(Playback),
+    /// Changes the subtitle style while a slider is dragged.
+    Subtitles(SubtitleStyle),
+    /// Stores the settings once a slider is let go.
+    Save,
+    SubtitleMode(SubtitleMode),
+    Library(Library),
+    PickFolder,
+    FolderPicked(Option<PathBuf>),
+    RemoveFolder(usize),
+    Appearance(Appearance),
+    /// Waits for the next key press to bind to the action.
+    Record(Action),
+    Recorded(Chord),
+    CancelRecord,
+    Bind(Action, Chord),
+    Unbind(Action, Chord),
+    ResetKeys,
+}
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    config: Config,
+    /// The names of the custom palettes.
+    themes: Vec<String>,
+    keymap: Keymap,
+    /// The action waiting for a key press.
+    recording: Option<Action>,
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self {
+            config: Config::load(),
+            themes: theme::custom(),
+            keymap: Keymap::load(),
+            recording: None,
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Whether key presses are being taken for a binding.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn update(&mut self, message: SettingsMessage) -> Task<SettingsMessage> {
+        match message {
+            SettingsMessage::Playback(playback) => {
+                self.config.playback = playback;
+                return Task::none();
+            }
+            SettingsMessage::Subtitles(style) => {
+                self.config.subtitles = style;
+                return Task::none();
+            }
+            SettingsMessage::Save => {}
+            SettingsMessage::SubtitleMode(mode) => self.config.languages.subtitle_mode = mode,
+            SettingsMessage::Library(library) => self.config.library = library,
+            SettingsMessage::PickFolder => {
+                return Task::perform(rfd::AsyncFileDialog::new().pick_folder(), |folder| {
+                    SettingsMessage::FolderPicked(folder.map(|folder| folder.path().to_owned()))
+                });
+            }
+            SettingsMessage::FolderPicked(None) => return Task::none(),
+            SettingsMessage::FolderPicked(Some(folder)) => {
+                if self.config.library.folders.contains(&folder) {
+                    return Task::none();
+                }
+                self.config.library.folders.push(folder);
+            }
+            SettingsMessage::RemoveFolder(index) => {
+                if index < self.config.library.folders.len() {
+                    self.config.library.folders.remove(index);
+                }
+            }
+            SettingsMessage::Appearance(appearance) => self.config.appearance = appearance,
+            SettingsMessage::Record(action) => {
+                self.recording = Some(action);
+                return Task::none();
+            }
+            SettingsMessage::CancelRecord => {
+                self.recording = None;
+                return Task::none();
+            }
+            SettingsMessage::Recorded(chord) => {
+                let Some(action) = self.recording.take() else {
+                    return Task::none();
+                };
+                self.keymap.bind(action, chord);
+                return self.save_keymap();
+            }
+            SettingsMessage::Bind(action, chord) => {
+                self.keymap.bind(action, chord);
+                return self.save_keymap();
+            }
+            SettingsMessage::Unbind(action, chord) => {
+                self.keymap.unbind(action, &chord);
+                return self.save_keymap();
+            }
+            SettingsMessage::ResetKeys => {
+                self.keymap = Keymap::default();
+                return self.save_keymap();
+            }
+        }
+
+        if let Err(err) = self.config.save() {
+            eprintln!("Error saving settings: \n{err}");
+        }
+        Task::none()
+    }
+
+    fn save_keymap(&self) -> Task<SettingsMessage> {
+        if let Err(err) = self.keymap.save() {
+            eprintln!("Error saving keymap: \n{err}");
+        }
+        Task::none()
+    }
+
+    pub fn subscription(&self) -> Subscription<SettingsMessage> {
+        if !self.is_recording() {
+            return Subscription::none();
+        }
+
+        event::listen_with(|event, _, _| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                Chord::from_key(&key, modifiers).map(SettingsMessage::Recorded)
+            }
+            _ => None,
+        })
+    }
+
+    pub fn view(&self) -> Element<'_, SettingsMessage> {
+        let content = column!(
+            self.playback(),
+            self.library(),
+            self.appearance(),
+            self.subtitles(),
+            self.keybindings(),
+        )
+        .spacing(28.0)
+        .padding(16.0)
+        .max_width(720.0);
+
+        container(scrollable(content))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn playback(&self) -> Element<'_, SettingsMessage> {
+        let playback = self.config.playback;
+
+        let step = |label: String,
+                    range: RangeInclusive<f64>,
+                    value: f64,
+                    step: f64,
+                    set: fn(Playback, f64) -> Playback,
+                    shown: String| {
+            setting(
+                label,
+                slider(range, value, move |value| {
+                    SettingsMessage::Playback(set(playback, value))
+                })
+                .step(step)
+                .on_release(SettingsMessage::Save),
+                shown,
+            )
+        };
+
+        let completion = slider(
+            Playback::COMPLETION,
+            playback.completion,
+            move |completion| {
+                SettingsMessage::Playback(Playback {
+                    completion,
+                    ..playback
+                })
+            },
+        )
+        .step(0.01)
+        .on_release(SettingsMessage::Save);
+
+        column!(
+            text(t!("settings-playback")).size(h5()),
+            step(
+                t!("settings-seek"),
+                Playback::SEEK,
+                playback.seek,
+                1.0,
+                |playback, seek| Playback { seek, ..playback },
+                format!("{:.0} s", playback.seek),
+            ),
+            step(
+                t!("settings-long-seek"),
+                Playback::LONG_SEEK,
+                playback.long_seek,
+                5.0,
+                |playback, long_seek| Playback {
+                    long_seek,
+                    ..playback
+                },
+                format!("{:.0} s", playback.long_seek),
+            ),
+            step(
+                t!("settings-speed-step"),
+                Playback::SPEED_STEP,
+                playback.speed_step,
+                0.05,
+                |playback, speed_step| Playback {
+                    speed_step,
+                    ..playback
+                },
+                format!("{:.2}×", playback.speed_step),
+            ),
+            step(
+                t!("settings-volume-step"),
+                Playback::VOLUME_STEP,
+                playback.volume_step,
+                0.01,
+                |playback, volume_step| Playback {
+                    volume_step,
+                    ..playback
+                },
+                format!("{:.0}%", playback.volume_step * 100.0),
+            ),
+            step(
+                t!("settings-default-volume"),
+                0.0..=1.0,
+                playback.default_volume,
+                0.05,
+                |playback, default_volume| Playback {
+                    default_volume,
+                    ..playback
+                },
+                format!("{:.0}%", playback.default_volume * 100.0),
+            ),
+            setting(
+                t!("settings-completion"),
+                completion,
+                format!("{:.0}%", playback.completion * 100.0)
+            ),
+        )
+        .spacing(10.0)
+        .into()
+    }
+
+    fn library(&self) -> Element<'_, SettingsMessage> {
+        let size = h7();
+        let library = &self.config.library;
+
+        let folders = library.folders.iter().enumerate().map(|(index, folder)| {
+            row!(
+                text(folder.display().to_string()).size(size),
+                horizontal_space(),
+                small(t!("common-remove"), SettingsMessage::RemoveFolder(index)),
+            )
+            .spacing(8.0)
+            .align_y(Vertical::Center)
+            .into()
+        });
+
+        let folders = if library.folders.is_empty() {
+            column!(text(t!("settings-no-folders")).size(size))
+        } else {
+            column(folders).spacing(4.0)
+        };
+
+        column!(
+            row!(
+                text(t!("settings-library")).size(h5()),
+                horizontal_space(),
+                small(t!("settings-add-folder"), SettingsMessage::PickFolder),
+            )
+            .align_y(Vertical::Center),
+            folders,
+            toggle(
+                t!("settings-recursive"),
+                library.recursive,
+                SettingsMessage::Library(Library {
+                    recursive: !library.recursive,
+                    ..library.clone()
+                }),
+            ),
+            toggle(
+                t!("settings-hidden"),
+                library.hidden,
+                SettingsMessage::Library(Library {
+                    hidden: !library.hidden,
+                    ..library.clone()
+                }),
+            ),
+        )
+        .spacing(10.0)
+        .into()
+    }
+
+    fn appearance(&self) -> Element<'_, SettingsMessage> {
+        let appearance = &self.config.appearance;
+
+        let layout = |label: String, view: ViewType| {
+            button(text(label).size(h7()))
+                .padding([3, 10])
+                .style(if appearance.view == view {
+                    button::primary
+                } else {
+                    button::secondary
+                })
+                .on_press(SettingsMessage::Appearance(Appearance {
+                    view,
+                    ..appearance.clone()
+                }))
+        };
+
+        let themes = [ThemeChoice::System, ThemeChoice::Light, ThemeChoice::Dark]
+            .into_iter()
+            .chain(self.themes.iter().cloned().map(ThemeChoice::Custom))
+            .collect::<Vec<_>>();
+        let theme = pick_list(themes, Some(appearance.theme.clone()), |theme| {
+            SettingsMessage::Appearance(Appearance {
+                theme,
+                ..self.config.appearance.clone()
+            })
+        })
+        .text_size(h7());
+
+        let language = pick_list(Language::ALL, Some(appearance.language), |language| {
+            SettingsMessage::Appearance(Appearance {
+                language,
+                ..self.config.appearance.clone()
+            })
+        })
+        .text_size(h7());
+
+        let folder = theme::folder()
+            .map(|folder| folder.display().to_string())
+            .map(|folder| t!("settings-themes-folder", folder = folder))
+            .unwrap_or_default();
+
+        let ui_scale = |step: f64| {
+            let (min, max) = Appearance::UI_SCALE.into_inner();
+            SettingsMessage::Appearance(Appearance {
+                ui_scale: (appearance.ui_scale + step).clamp(min, max),
+                ..appearance.clone()
+            })
+        };
+        let text_scale = |step: f32| {
+            let (min, max) = Appearance::TEXT_SCALE.into_inner();
+            SettingsMessage::Appearance(Appearance {
+                text_scale: (appearance.text_scale + step).clamp(min, max),
+                ..appearance.clone()
+            })
+        };
+
+        column!(
+            text(t!("settings-appearance")).size(h5()),
+            row!(
+                text(t!("language")).size(h7()).width(180.0),
+                horizontal_space(),
+                language
+            )
+            .align_y(Vertical::Center),
+            row!(
+                text(t!("settings-theme")).size(h7()).width(180.0),
+                horizontal_space(),
+                theme
+            )
+            .align_y(Vertical::Center),
+            text(folder).size(h7()),
+            stepper(
+                t!("settings-ui-scale"),
+                format!("{:.0}%", appearance.ui_scale * 100.0),
+                ui_scale(-0.25),
+                ui_scale(0.25),
+            ),
+            stepper(
+                t!("settings-text-scale"),
+                format!("{:.0}%", appearance.text_scale * 100.0),
+                text_scale(-0.1),
+                text_scale(0.1),
+            ),
+            row!(
+                text(t!("settings-layout")).size(h7()).width(180.0),
+                horizontal_space(),
+                layout(t!("settings-grid"), ViewType::Grid),
+                layout(t!("settings-list"), ViewType::List),
+            )
+            .spacing(6.0)
+            .align_y(Vertical::Center),
+        )
+        .spacing(10.0)
+        .into()
+    }
+
+    fn subtitles(&self) -> Element<'_, SettingsMessage> {
+        let style = self.config.subtitles.clone();
+        let size = {
+            let style = style.clone();
+            slider(0.02..=0.1, style.size, move |size| {
+                SettingsMessage::Subtitles(SubtitleStyle {
+                    size,
+                    ..style.clone()
+                })
+            })
+            .step(0.005)
+            .on_release(SettingsMessage::Save)
+        };
+        let position = {
+            let style = style.clone();
+            slider(0.0..=0.3, style.position, move |position| {
+                SettingsMessage::Subtitles(SubtitleStyle {
+                    position,
+                    ..style.clone()
+                })
+            })
+            .step(0.01)
+            .on_release(SettingsMessage::Save)
+        };
+        let background = {
+            let style = style.clone();
+            slider(0.0..=1.0, style.background, move |background| {
+                SettingsMessage::Subtitles(SubtitleStyle {
+                    background,
+                    ..style.clone()
+                })
+            })
+            .step(0.05)
+            .on_release(SettingsMessage::Save)
+        };
+
+        let mode = pick_list(
+            SubtitleMode::ALL,
+            Some(self.config.languages.subtitle_mode),
+            SettingsMessage::SubtitleMode,
+        )
+        .text_size(h7());
+
+        column!(
+            text(t!("settings-subtitles")).size(h5()),
+            row!(
+                text(t!("settings-subtitles-show")).size(h7()).width(180.0),
+                horizontal_space(),
+                mode
+            )
+            .align_y(Vertical::Center),
+            setting(
+                t!("settings-subtitles-size"),
+                size,
+                format!("{:.1}%", style.size * 100.0)
+            ),
+            setting(
+                t!("settings-subtitles-position"),
+                position,
+                format!("{:.0}%", style.position * 100.0)
+            ),
+            setting(
+                t!("settings-subtitles-background"),
+                background,
+                format!("{:.0}%", style.background * 100.0)
+            ),
+        )
+        .spacing(10.0)
+        .into()
+    }
+
+    fn keybindings(&self) -> Element<'_, SettingsMessage> {
+        let conflicts = self.keymap.conflicts();
+
+        let bindings = |scope: Scope| {
+            Action::ALL
+                .into_iter()
+                .filter(move |action| action.scope() == scope)
+                .map(|action| self.binding(action, &conflicts))
+                .collect::<Vec<_>>()
+        };
+
+        let warnings = conflicts.iter().map(|(chord, actions)| {
+            let actions = actions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            text(t!(
+                "settings-key-conflict",
+                chord = chord.to_string(),
+                actions = actions
+            ))
+            .size(h7())
+            .color(CONFLICT_COLOR)
+            .into()
+        });
+
+        column!(
+            row!(
+                text(t!("settings-keybindings")).size(h5()),
+                horizontal_space(),
+                small(t!("settings-reset-keys"), SettingsMessage::ResetKeys),
+            )
+            .align_y(Vertical::Center),
+            column(warnings).spacing(2.0),
+            text(t!("settings-keys-player")).size(h6()),
+            column(bindings(Scope::Player)).spacing(4.0),
+            text(t!("settings-keys-library")).size(h6()),
+            column(bindings(Scope::Library)).spacing(4.0),
+        )
+        .spacing(12.0)
+        .into()
+    }
+
+    fn binding(
+        &self,
+        action: Action,
+        conflicts: &BTreeMap<Chord, Vec<Action>>,
+    ) -> Element<'_, SettingsMessage> {
+        let size = h7();
+
+        let chords = self.keymap.chords(action).iter().map(|chord| {
+            let label = text(chord.to_string()).size(size);
+            let label = if conflicts.contains_key(chord) {
+                label.color(CONFLICT_COLOR)
+            } else {
+                label
+            };
+
+            button(row!(label, text("×").size(size)).spacing(6.0))
+                .padding([2, 8])
+                .style(button::secondary)
+                .on_press(SettingsMessage::Unbind(action, chord.clone()))
+                .into()
+        });
+
+        let record = if self.recording == Some(action) {
+            button(text(t!("settings-press-key")).size(size))
+                .padding([2, 8])
+                .style(button::primary)
+                .on_press(SettingsMessage::CancelRecord)
+        } else {
+            button(text(t!("settings-add-key")).size(size))
+                .padding([2, 8])
+                .style(button::text)
+                .on_press(SettingsMessage::Record(action))
+        };
+
+        let mut content = row!(
+            text(action.to_string()).size(size).width(180.0),
+            row(chords).spacing(4.0),
+            horizontal_space(),
+            record,
+        )
+        .spacing(8.0)
+        .align_y(Vertical::Center);
+
+        if action.scope() == Scope::Player {
+            let click = pick_list(Chord::CLICKS, None::<&str>, move |click| {
+                SettingsMessage::Bind(action, Chord::click(click))
+            })
+            .placeholder(t!("settings-add-click"))
+            .text_size(size);
+
+            content = content.push(click);
+        }
+
+        content.into()
+    }
+}
+
+/// A labelled control with its current value.
+fn setting<'a>(
+    label: impl text::IntoFragment<'a>,
+    control: impl Into<Element<'a, SettingsMessage>>,
+    value: String,
+) -> Element<'a, SettingsMessage> {
+    row!(
+        text(label).size(h7()).width(180.0),
+        control.into(),
+        text(value).size(h7()).width(56.0),
+    )
+    .spacing(8.0)
+    .align_y(Vertical::Center)
+    .into()
+}
+
+/// A value changed a step at a time, for values whose change moves the
+/// control itself.
+fn stepper<'a>(
+    label: impl text::IntoFragment<'a>,
+    value: String,
+    decrease: SettingsMessage,
+    increase: SettingsMessage,
+) -> Element<'a, SettingsMessage> {
+    row!(
+        text(label).size(h7()).width(180.0),
+        horizontal_space(),
+        small("-", decrease),
+        text(value).size(h7()).width(56.0).center(),
+        small("+", increase),
+    )
+    .spacing(6.0)
+    .align_y(Vertical::Center)
+    .into()
+}
+
+fn toggle<'a>(
+    label: impl text::IntoFragment<'a>,
+    on: bool,
+    message: SettingsMessage,
+) -> Element<'a, SettingsMessage> {
+    let state = if on {
+        t!("common-on")
+    } else {
+        t!("common-off")
+    };
+
+    row!(
+        text(label).size(h7()),
+        horizontal_space(),
+        small(state, message),
+    )
+    .align_y(Vertical::Center)
+    .into()
+}
+
+fn small<'a>(
+    label: impl text::IntoFragment<'a>,
+    message: SettingsMessage,
+) -> button::Button<'a, SettingsMessage> {
+    button(text(label).size(h7()))
+        .padding([3, 10])
+        .style(button::secondary)
+        .on_press(message)
+}
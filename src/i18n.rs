@@ -0,0 +1,134 @@
+//! Translations of the interface, from the Fluent message files in
+//! `assets/locales`.
+//!
+//! Text is looked up with [`t!`](crate::t), which takes the id of a message
+//! and any variables it uses:
+//!
+//! ```ignore
+//! text(t!("queue-title", count = queue.len()))
+//! ```
+use fluent_bundle::{FluentResource, concurrent::FluentBundle};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::sync::{LazyLock, RwLock};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+/// A language the interface is translated into.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Self; 2] = [Self::English, Self::French];
+
+    fn id(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::French => "fr",
+        }
+    }
+
+    fn messages(&self) -> &'static str {
+        match self {
+            Self::English => include_str!("../assets/locales/en.ftl"),
+            Self::French => include_str!("../assets/locales/fr.ftl"),
+        }
+    }
+
+    fn bundle(&self) -> FluentBundle<FluentResource> {
+        let id: LanguageIdentifier = self.id().parse().expect("Invalid language id");
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // The marks isolating variables from the text around them are not
+        // drawn well by every font.
+        bundle.set_use_isolating(false);
+
+        let resource = match FluentResource::try_new(self.messages().to_owned()) {
+            Ok(resource) => resource,
+            Err((resource, errors)) => {
+                eprintln!("Errors in the {self} messages: \n{errors:?}");
+                resource
+            }
+        };
+        if let Err(errors) = bundle.add_resource(resource) {
+            eprintln!("Errors adding the {self} messages: \n{errors:?}");
+        }
+
+        bundle
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Each language is named in itself, so it can be found by its speakers.
+        let name = match self {
+            Self::English => "English",
+            Self::French => "Français",
+        };
+        write!(f, "{name}")
+    }
+}
+
+struct Translations {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    /// Where messages missing from `bundle` are taken from.
+    fallback: FluentBundle<FluentResource>,
+}
+
+static TRANSLATIONS: LazyLock<RwLock<Translations>> = LazyLock::new(|| {
+    RwLock::new(Translations {
+        language: Language::English,
+        bundle: Language::English.bundle(),
+        fallback: Language::English.bundle(),
+    })
+});
+
+/// Switches the interface to `language`.
+pub fn set_language(language: Language) {
+    let mut translations = TRANSLATIONS.write().unwrap_or_else(|err| err.into_inner());
+    if translations.language != language {
+        translations.language = language;
+        translations.bundle = language.bundle();
+    }
+}
+
+/// The message `id` in the current language, falling back to English and
+/// then to the id itself.
+///
+/// Use [`t!`](crate::t) rather than calling this directly.
+pub fn message(id: &str, args: Option<&FluentArgs>) -> String {
+    let translations = TRANSLATIONS.read().unwrap_or_else(|err| err.into_inner());
+
+    [&translations.bundle, &translations.fallback]
+        .into_iter()
+        .find_map(|bundle| {
+            let pattern = bundle.get_message(id)?.value()?;
+            let mut errors = vec![];
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+
+            if !errors.is_empty() {
+                eprintln!("Errors formatting message {id}: \n{errors:?}");
+            }
+            Some(text.into_owned())
+        })
+        .unwrap_or_else(|| id.to_owned())
+}
+
+/// Translates a message, given its id and any variables it uses.
+#[macro_export]
+macro_rules! t {
+    ($id:literal) => {
+        $crate::i18n::message($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::message($id, Some(&args))
+    }};
+}
@@ -0,0 +1,481 @@
+//! Key and mouse bindings, stored in `keymap.toml` beside the settings.
+use iced::keyboard::{self, key};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::*;
+use crate::t;
+
+/// Where an action can be triggered from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Player,
+    Library,
+}
+
+/// Something a key or mouse chord can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Action {
+    TogglePlay,
+    ToggleFullscreen,
+    ExitFullscreen,
+    SeekBack,
+    SeekBackFar,
+    SeekForward,
+    SeekForwardFar,
+    VolumeUp,
+    VolumeDown,
+    ToggleMute,
+    SpeedUp,
+    SlowDown,
+    ResetSpeed,
+    StepForward,
+    StepBackward,
+    PreviousChapter,
+    NextChapter,
+    PlayPrevious,
+    PlayNext,
+    ToggleQueue,
+    ToggleSubtitles,
+    SubtitlesEarlier,
+    SubtitlesLater,
+    AudioEarlier,
+    AudioLater,
+    CycleLoop,
+    LoopStartEarlier,
+    LoopStartLater,
+    LoopEndEarlier,
+    LoopEndLater,
+    Screenshot,
+    AddBookmark,
+    SetClipIn,
+    SetClipOut,
+    MarkCredits,
+    ShowConfig,
+    LeavePlayer,
+    Back,
+    Forward,
+    PlayQueue,
+    ToggleView,
+    Randomize,
+    Home,
+    Settings,
+}
+
+impl Action {
+    pub const ALL: [Self; 44] = [
+        Self::TogglePlay,
+        Self::ToggleFullscreen,
+        Self::ExitFullscreen,
+        Self::SeekBack,
+        Self::SeekBackFar,
+        Self::SeekForward,
+        Self::SeekForwardFar,
+        Self::VolumeUp,
+        Self::VolumeDown,
+        Self::ToggleMute,
+        Self::SpeedUp,
+        Self::SlowDown,
+        Self::ResetSpeed,
+        Self::StepForward,
+        Self::StepBackward,
+        Self::PreviousChapter,
+        Self::NextChapter,
+        Self::PlayPrevious,
+        Self::PlayNext,
+        Self::ToggleQueue,
+        Self::ToggleSubtitles,
+        Self::SubtitlesEarlier,
+        Self::SubtitlesLater,
+        Self::AudioEarlier,
+        Self::AudioLater,
+        Self::CycleLoop,
+        Self::LoopStartEarlier,
+        Self::LoopStartLater,
+        Self::LoopEndEarlier,
+        Self::LoopEndLater,
+        Self::Screenshot,
+        Self::AddBookmark,
+        Self::SetClipIn,
+        Self::SetClipOut,
+        Self::MarkCredits,
+        Self::ShowConfig,
+        Self::LeavePlayer,
+        Self::Back,
+        Self::Forward,
+        Self::PlayQueue,
+        Self::ToggleView,
+        Self::Randomize,
+        Self::Home,
+        Self::Settings,
+    ];
+
+    pub fn scope(&self) -> Scope {
+        match self {
+            Self::Back
+            | Self::Forward
+            | Self::PlayQueue
+            | Self::ToggleView
+            | Self::Randomize
+            | Self::Home
+            | Self::Settings => Scope::Library,
+            _ => Scope::Player,
+        }
+    }
+
+    /// The chords bound to the action out of the box.
+    fn defaults(&self) -> &'static [&'static str] {
+        match self {
+            Self::TogglePlay => &["Space", "Click"],
+            Self::ToggleFullscreen => &["Enter", "f", "DoubleClick"],
+            Self::ExitFullscreen => &["Escape"],
+            Self::SeekBack => &["ArrowLeft"],
+            Self::SeekBackFar => &["Shift+ArrowLeft"],
+            Self::SeekForward => &["ArrowRight"],
+            Self::SeekForwardFar => &["Shift+ArrowRight"],
+            Self::VolumeUp => &["ArrowUp"],
+            Self::VolumeDown => &["ArrowDown"],
+            Self::ToggleMute => &["m"],
+            Self::SpeedUp => &["c"],
+            Self::SlowDown => &["x"],
+            Self::ResetSpeed => &["z"],
+            Self::StepForward => &["."],
+            Self::StepBackward => &[","],
+            Self::PreviousChapter => &["PageUp"],
+            Self::NextChapter => &["PageDown"],
+            Self::PlayPrevious => &["P"],
+            Self::PlayNext => &["N"],
+            Self::ToggleQueue => &["q"],
+            Self::ToggleSubtitles => &["v"],
+            Self::SubtitlesEarlier => &["g"],
+            Self::SubtitlesLater => &["h"],
+            Self::AudioEarlier => &["j"],
+            Self::AudioLater => &["k"],
+            Self::CycleLoop => &["l"],
+            Self::LoopStartEarlier => &["["],
+            Self::LoopStartLater => &["]"],
+            Self::LoopEndEarlier => &["{"],
+            Self::LoopEndLater => &["}"],
+            Self::Screenshot => &["s"],
+            Self::AddBookmark => &["b"],
+            Self::SetClipIn => &["i"],
+            Self::SetClipOut => &["o"],
+            Self::MarkCredits => &["e"],
+            Self::ShowConfig => &["RightClick"],
+            Self::LeavePlayer => &["Backspace"],
+            Self::Back => &["Alt+ArrowLeft"],
+            Self::Forward => &["Alt+ArrowRight"],
+            Self::PlayQueue => &[],
+            Self::ToggleView => &[],
+            Self::Randomize => &[],
+            Self::Home => &["Alt+Home"],
+            Self::Settings => &["Ctrl+,"],
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::TogglePlay => t!("action-toggle-play"),
+                Self::ToggleFullscreen => t!("action-toggle-fullscreen"),
+                Self::ExitFullscreen => t!("action-exit-fullscreen"),
+                Self::SeekBack => t!("action-seek-back"),
+                Self::SeekBackFar => t!("action-seek-back-far"),
+                Self::SeekForward => t!("action-seek-forward"),
+                Self::SeekForwardFar => t!("action-seek-forward-far"),
+                Self::VolumeUp => t!("action-volume-up"),
+                Self::VolumeDown => t!("action-volume-down"),
+                Self::ToggleMute => t!("action-toggle-mute"),
+                Self::SpeedUp => t!("action-speed-up"),
+                Self::SlowDown => t!("action-slow-down"),
+                Self::ResetSpeed => t!("action-reset-speed"),
+                Self::StepForward => t!("action-step-forward"),
+                Self::StepBackward => t!("action-step-backward"),
+                Self::PreviousChapter => t!("action-previous-chapter"),
+                Self::NextChapter => t!("action-next-chapter"),
+                Self::PlayPrevious => t!("action-play-previous"),
+                Self::PlayNext => t!("action-play-next"),
+                Self::ToggleQueue => t!("action-toggle-queue"),
+                Self::ToggleSubtitles => t!("action-toggle-subtitles"),
+                Self::SubtitlesEarlier => t!("action-subtitles-earlier"),
+                Self::SubtitlesLater => t!("action-subtitles-later"),
+                Self::AudioEarlier => t!("action-audio-earlier"),
+                Self::AudioLater => t!("action-audio-later"),
+                Self::CycleLoop => t!("action-cycle-loop"),
+                Self::LoopStartEarlier => t!("action-loop-start-earlier"),
+                Self::LoopStartLater => t!("action-loop-start-later"),
+                Self::LoopEndEarlier => t!("action-loop-end-earlier"),
+                Self::LoopEndLater => t!("action-loop-end-later"),
+                Self::Screenshot => t!("action-screenshot"),
+                Self::AddBookmark => t!("action-add-bookmark"),
+                Self::SetClipIn => t!("action-set-clip-in"),
+                Self::SetClipOut => t!("action-set-clip-out"),
+                Self::MarkCredits => t!("action-mark-credits"),
+                Self::ShowConfig => t!("action-show-config"),
+                Self::LeavePlayer => t!("action-leave-player"),
+                Self::Back => t!("action-back"),
+                Self::Forward => t!("action-forward"),
+                Self::PlayQueue => t!("action-play-queue"),
+                Self::ToggleView => t!("action-toggle-view"),
+                Self::Randomize => t!("action-randomize"),
+                Self::Home => t!("action-home"),
+                Self::Settings => t!("action-settings"),
+            }
+        )
+    }
+}
+
+/// A key or mouse button pressed along with modifiers, written like
+/// `Ctrl+Shift+ArrowLeft` or `DoubleClick`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Chord {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub logo: bool,
+    /// A named key such as `ArrowLeft`, the character of a key, or a click.
+    pub key: String,
+}
+
+impl Chord {
+    /// The names of the mouse buttons which can be bound.
+    pub const CLICKS: [&str; 4] = ["Click", "DoubleClick", "RightClick", "MiddleClick"];
+
+    /// The chord of a key press, `None` for modifier keys on their own.
+    ///
+    /// Shift is left out for characters, which already tell it apart: Shift
+    /// and `[` gives `{`.
+    pub fn from_key(key: &keyboard::Key, modifiers: keyboard::Modifiers) -> Option<Self> {
+        let (key, is_character) = match key {
+            keyboard::Key::Named(
+                key::Named::Control
+                | key::Named::Alt
+                | key::Named::Shift
+                | key::Named::Super
+                | key::Named::Meta,
+            ) => return None,
+            keyboard::Key::Named(named) => (format!("{named:?}"), false),
+            keyboard::Key::Character(character) => (character.to_string(), true),
+            keyboard::Key::Unidentified => return None,
+        };
+
+        Some(Self {
+            ctrl: modifiers.control(),
+            alt: modifiers.alt(),
+            shift: modifiers.shift() && !is_character,
+            logo: modifiers.logo(),
+            key,
+        })
+    }
+
+    /// A mouse click, named as in [`Chord::CLICKS`].
+    pub fn click(name: &str) -> Self {
+        Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            logo: false,
+            key: name.to_owned(),
+        }
+    }
+
+    pub fn is_click(&self) -> bool {
+        Self::CLICKS.contains(&self.key.as_str())
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (held, name) in [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.logo, "Super"),
+        ] {
+            if held {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        write!(f, "{}", self.key)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let mut chord = Self::click("");
+        let mut rest = input.trim();
+
+        // The key itself may be `+`, so only known modifiers are split off.
+        loop {
+            let Some((modifier, key)) = rest.split_once('+').filter(|(_, key)| !key.is_empty())
+            else {
+                break;
+            };
+
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" => chord.alt = true,
+                "shift" => chord.shift = true,
+                "super" | "logo" | "cmd" => chord.logo = true,
+                _ => break,
+            }
+            rest = key;
+        }
+
+        if rest.is_empty() {
+            return Err(format!("Missing key in \"{input}\""));
+        }
+
+        // Key presses carry Shift in the character itself, see
+        // `Chord::from_key`.
+        let mut characters = rest.chars();
+        if let (true, Some(character), None) = (chord.shift, characters.next(), characters.next()) {
+            if !character.is_alphabetic() {
+                return Err(format!(
+                    "Write the shifted character instead of \"{input}\", such as {{ for Shift+["
+                ));
+            }
+
+            chord.shift = false;
+            chord.key = character.to_uppercase().collect();
+            return Ok(chord);
+        }
+
+        chord.key = rest.to_owned();
+        Ok(chord)
+    }
+}
+
+impl TryFrom<String> for Chord {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Chord> for String {
+    fn from(value: Chord) -> Self {
+        value.to_string()
+    }
+}
+
+/// The chords bound to each action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Keymap {
+    pub keys: BTreeMap<Action, Vec<Chord>>,
+}
+
+impl Keymap {
+    /// Loads the keymap, with defaults for actions it leaves out.
+    pub fn load() -> Self {
+        let path = match config::config_file("keymap.toml") {
+            Ok(path) => path,
+            Err(err) => {
+                eprintln!("Error loading keymap: \n{err}");
+                return Self::default();
+            }
+        };
+
+        match config::read::<Self>(&path) {
+            Ok(stored) => {
+                let mut keymap = Self::default();
+                keymap.keys.extend(stored.keys);
+                keymap
+            }
+            Err(err) => {
+                eprintln!("Error loading keymap: \n{err}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        config::write(&config::config_file("keymap.toml")?, self)
+    }
+
+    /// The action `chord` triggers within `scope`.
+    pub fn action(&self, chord: &Chord, scope: Scope) -> Option<Action> {
+        self.keys
+            .iter()
+            .find(|(action, chords)| action.scope() == scope && chords.contains(chord))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn chords(&self, action: Action) -> &[Chord] {
+        self.keys
+            .get(&action)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Binds `chord` to `action`, on top of its other chords.
+    pub fn bind(&mut self, action: Action, chord: Chord) {
+        let chords = self.keys.entry(action).or_default();
+
+        if !chords.contains(&chord) {
+            chords.push(chord);
+        }
+    }
+
+    pub fn unbind(&mut self, action: Action, chord: &Chord) {
+        if let Some(chords) = self.keys.get_mut(&action) {
+            chords.retain(|bound| bound != chord);
+        }
+    }
+
+    /// Chords bound to more than one action of the same scope, with those
+    /// actions.
+    pub fn conflicts(&self) -> BTreeMap<Chord, Vec<Action>> {
+        let mut bound = BTreeMap::<Chord, Vec<Action>>::new();
+
+        for (action, chords) in &self.keys {
+            for chord in chords {
+                bound.entry(chord.clone()).or_default().push(*action);
+            }
+        }
+
+        bound
+            .into_iter()
+            .filter(|(_, actions)| {
+                actions.iter().any(|action| {
+                    actions
+                        .iter()
+                        .any(|other| other != action && other.scope() == action.scope())
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let keys = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let chords = action
+                    .defaults()
+                    .iter()
+                    .filter_map(|chord| chord.parse().ok())
+                    .collect();
+
+                (action, chords)
+            })
+            .collect();
+
+        Self { keys }
+    }
+}
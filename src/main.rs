@@ -20,13 +20,19 @@ use iced::{
 };
 
 mod app;
+mod cli;
+mod config;
 mod error;
 mod home;
+mod i18n;
+mod keymap;
 mod player;
+mod theme;
 pub mod utils;
 mod video;
 mod widgets;
 
+use clap::Parser;
 use player::{Player, PlayerMessage};
 use utils::filter;
 use utils::filter::*;
@@ -53,16 +59,18 @@ fn _test_main() {
 
 // fn test_main() -> iced::Result {
 fn main() -> iced::Result {
+    // Invalid arguments end the program here, with a message saying why.
+    let args = cli::Args::parse();
+
     // iced::run(app::App::update, app::App::view)
-    // iced::application(Player::boot, Player::update, Player::view)
-    //     .subscription(Player::subscriptions)
-    //     .run()
     iced::application::timed(
-        home::Home::boot,
+        move || home::Home::boot(args.clone()),
         home::Home::update,
         home::Home::subscription,
         home::Home::view,
     )
+    .theme(home::Home::theme)
+    .scale_factor(home::Home::scale_factor)
     .window_size(Size::new(1200.0, 750.0))
     .run()
 
@@ -1,23 +1,155 @@
+use gstreamer as gst;
 use iced::{
-    Element, Font, Length, Size, Subscription, Task,
+    Color, ContentFit, Element, Event, Font, Length, Padding, Point, Size, Subscription, Task,
     alignment::{Horizontal, Vertical},
-    font,
+    border::Border,
+    event, font, keyboard, mouse,
     widget::{
-        column, container, horizontal_space, image, mouse_area, row, slider, stack, text,
-        vertical_space,
+        button, center_x, column, container, horizontal_space, image, mouse_area, pick_list, pin,
+        progress_bar, responsive, row, scrollable, slider, stack, text, text_input,
+        vertical_slider, vertical_space,
     },
     window,
 };
-use iced_video_player::{Button, Icon, KeyPress, Kind, MouseClick, Video, VideoPlayer, key};
+use iced_video_player::{Button, Icon, KeyPress, Kind, MouseClick, Video, VideoPlayer};
 use std::path::PathBuf;
-use std::time::Duration;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::{Duration, Instant};
+
+mod adjustments;
+mod audio;
+mod chapters;
+pub mod export;
+mod framing;
+mod letterbox;
+mod queue;
+mod screenshot;
+mod subtitles;
+mod tracks;
+
+use chapters::Chapter;
+pub use queue::{Queue, Repeat};
+use subtitles::Subtitles;
+use tracks::{Track, TrackKind};
+
+use crate::error;
+use crate::keymap::{Action, Chord, Keymap, Scope};
+use crate::t;
+use crate::theme;
+
+use crate::config::{
+    Adjustments, AspectRatio, AudioFilters, Config, Crop, EqualizerPreset, Fit, Framing, Languages,
+    SubtitleChoice, SubtitleMode, VideoConfig,
+};
 
 use crate::utils::{
     self,
-    icons::{self, text_button},
-    load_fonts,
+    icons::{self, sized_button, text_button},
+    typo::*,
 };
-use crate::widgets;
+use crate::video::{self, Bookmark};
+use crate::widgets::{self, slider::Marker};
+
+const LOOP_A_COLOR: Color = Color::from_rgb(0.3, 0.8, 0.45);
+const LOOP_B_COLOR: Color = Color::from_rgb(0.9, 0.35, 0.3);
+const CLIP_COLOR: Color = Color::from_rgb(0.35, 0.6, 0.95);
+const BOOKMARK_COLOR: Color = Color::from_rgb(0.95, 0.75, 0.25);
+
+#[derive(Debug, Clone, Copy)]
+pub enum LoopMessage {
+    /// Sets A, then B, then clears the loop.
+    Cycle,
+    SetA,
+    SetB,
+    Clear,
+    /// Moves A by the given seconds.
+    NudgeA(f64),
+    /// Moves B by the given seconds.
+    NudgeB(f64),
+}
+
+#[derive(Debug, Clone)]
+pub enum BookmarkMessage {
+    /// Bookmarks the current position.
+    Add,
+    Jump(usize),
+    StartRename(usize),
+    Rename(String),
+    FinishRename,
+    Delete(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum ExportMessage {
+    Toggle,
+    SetIn,
+    SetOut,
+    Clear,
+    Format(export::Format),
+    Width(u32),
+    Fps(u32),
+    /// Asks where to save the export.
+    Start,
+    Destination(Option<PathBuf>),
+    Progress(export::Progress),
+    Cancel,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AdjustMessage {
+    Set(Adjustments),
+    Reset,
+    /// Saves the adjustments for all videos without their own.
+    SaveGlobally,
+    SaveForVideo,
+}
+
+#[derive(Debug, Clone)]
+pub enum FrameMessage {
+    Aspect(AspectRatio),
+    /// Edits the custom aspect ratio, applied once it parses.
+    CustomAspect(String),
+    Fit(Fit),
+    /// Scrolled lines of the mouse wheel, which zoom while the command key is
+    /// held.
+    Wheel(f32),
+    ResetZoom,
+    Crop(Crop),
+    ResetCrop,
+    /// Turns cropping of black bars on or off for all videos.
+    AutoCrop(bool),
+    Modifiers(keyboard::Modifiers),
+    PanStart,
+    Pan(Point),
+    PanEnd,
+    /// Stores the framing once a slider is let go of or an edit is committed.
+    Save,
+}
+
+#[derive(Debug, Clone)]
+pub enum AudioMessage {
+    NightMode(bool),
+    Normalize(bool),
+    Preset(EqualizerPreset),
+    /// Sets the gain of the band at the index, in decibels.
+    Band(usize, f32),
+    /// Stores the filters once a band is let go of.
+    Save,
+}
+
+#[derive(Debug, Clone)]
+pub enum QueueMessage {
+    Toggle,
+    Shuffle,
+    Repeat,
+    Jump(usize),
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
 
 #[derive(Debug, Clone)]
 pub enum PlayerMessage {
@@ -39,9 +171,45 @@ pub enum PlayerMessage {
     AddCollection,
     Config,
     ToggleSubtitles,
+    SubtitlesLoaded(Vec<Subtitles>, bool),
+    SelectSubtitles(Option<SubtitleSource>),
+    SelectSecondarySubtitles(Option<SubtitleSource>),
+    /// Shifts subtitles by the given milliseconds.
+    SubtitleDelay(i64),
+    /// Shifts the audio by the given milliseconds.
+    AudioDelay(i64),
+    SelectAudio(i32),
+    SubtitleText(Option<String>),
+    PickSubtitles,
+    SubtitlesPicked(Option<PathBuf>),
+    ChaptersLoaded(Vec<Chapter>),
+    /// The black bars found in the video at the path, `None` when looking
+    /// for them failed.
+    CropDetected(PathBuf, Option<Crop>),
+    PreviousChapter,
+    NextChapter,
+    SeekChapter(usize),
+    Loop(LoopMessage),
+    /// Steps a single frame forward, or backward when `false`.
+    StepFrame(bool),
+    Screenshot,
+    ScreenshotSaved(Result<PathBuf, String>),
+    /// The sound filters could not be added, so they have no effect.
+    AudioFiltersFailed(String),
+    /// The video has more audio or subtitle streams than first found.
+    TracksChanged,
+    /// Sets the poster of the current video.
+    UseAsPoster(PathBuf),
+    DismissToast,
+    Export(ExportMessage),
+    Bookmark(BookmarkMessage),
+    Adjust(AdjustMessage),
+    Frame(FrameMessage),
+    Audio(AudioMessage),
     ToggleMute,
     PlayPrevious,
     PlayNext,
+    Queue(QueueMessage),
     SeekFront(bool),
     SeekBack(bool),
     TogglePlay,
@@ -51,20 +219,115 @@ pub enum PlayerMessage {
     ExitFullscreen,
     EndOfStream,
     NewFrame,
+    Countdown,
+    PlayNow,
+    CancelNext,
+    MarkCredits,
+    /// Clears expired OSD messages and toasts.
+    Tick,
+    /// A key or mouse chord, carried out through the keymap.
+    Chord(Chord),
     None,
 }
 
+/// Where the shown subtitles come from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubtitleSource {
+    /// A loaded subtitle file, by index.
+    External(usize),
+    /// A subtitle stream of the container, by index.
+    Embedded(i32),
+}
+
+impl SubtitleSource {
+    fn is_embedded(&self) -> bool {
+        matches!(self, Self::Embedded(_))
+    }
+}
+
+/// A notification in the corner of the player.
+#[derive(Debug, Clone)]
+struct Toast {
+    message: String,
+    /// A screenshot which can be used as the poster of the video.
+    screenshot: Option<PathBuf>,
+    shown: Instant,
+}
+
+/// An export running in the background.
+#[derive(Debug, Clone)]
+struct ExportJob {
+    progress: f32,
+    cancel: Arc<AtomicBool>,
+}
+
+/// The card counting down to the next video in the queue.
+#[derive(Debug, Clone)]
+struct UpNext {
+    remaining: u64,
+    poster: Option<image::Handle>,
+}
+
 #[derive(Debug)]
 pub struct Player {
     position: f64,
     show_controls: bool,
     maximised: bool,
     is_fullscreen: bool,
+    /// From 0 to [`Player::MAX_VOLUME`], where anything above 1 is a boost.
     volume: f64,
+    /// The gain above full volume, kept while muted.
+    boost: f64,
     speed: f64,
-    has_previous: bool,
-    has_next: bool,
-    path: PathBuf,
+    queue: Queue,
+    show_queue: bool,
+    up_next: Option<UpNext>,
+    /// Whether auto-play was cancelled for the current video.
+    up_next_cancelled: bool,
+    subtitles: Vec<Subtitles>,
+    subtitle: Option<SubtitleSource>,
+    /// The subtitles shown at the top, such as a language being learnt.
+    secondary: Option<SubtitleSource>,
+    show_subtitles: bool,
+    cue: Option<String>,
+    secondary_cue: Option<String>,
+    subtitle_font: Font,
+    /// Short feedback shown over the video and when it was shown.
+    osd: Option<(String, Instant)>,
+    toast: Option<Toast>,
+    audio_tracks: Vec<Track>,
+    text_tracks: Vec<Track>,
+    audio: i32,
+    chapters: Vec<Chapter>,
+    /// The start of the section being repeated, in seconds.
+    loop_a: Option<f64>,
+    /// The end of the section being repeated, in seconds.
+    loop_b: Option<f64>,
+    /// The start of the section to export, in seconds.
+    clip_in: Option<f64>,
+    /// The end of the section to export, in seconds.
+    clip_out: Option<f64>,
+    show_export: bool,
+    show_adjustments: bool,
+    /// The picture adjustments in effect, which may not be saved yet.
+    adjustments: Adjustments,
+    custom_aspect: String,
+    modifiers: keyboard::Modifiers,
+    /// Whether the wheel zoomed since the command key was pressed.
+    zooming: bool,
+    /// Whether the picture is being dragged, and where the cursor last was.
+    panning: Option<Option<Point>>,
+    window_size: Size,
+    export_format: export::Format,
+    export_width: u32,
+    export_fps: u32,
+    export_job: Option<ExportJob>,
+    /// The bookmark being renamed and its new name.
+    renaming: Option<(usize, String)>,
+    /// Choices remembered for the current video.
+    config: VideoConfig,
+    settings: Config,
+    keymap: Keymap,
     video: Video,
     thumbnails: Vec<image::Handle>,
     is_dragging: bool,
@@ -73,15 +336,118 @@ pub struct Player {
 
 impl Player {
     const WIDTH: f32 = 150.0;
+    const MAX_VOLUME: f64 = 2.0;
+    /// Seconds before the next video in the queue plays automatically.
+    const COUNTDOWN: u64 = 10;
+    const OSD_DURATION: Duration = Duration::from_millis(1500);
+    const TOAST_DURATION: Duration = Duration::from_secs(8);
+    const EXPORT_WIDTHS: [u32; 4] = [320, 480, 640, 800];
+    const EXPORT_FPS: [u32; 4] = [10, 15, 24, 30];
+    /// Seconds a loop marker moves by when nudged.
+    const LOOP_NUDGE: f64 = 0.1;
+    /// Milliseconds the subtitle delay changes by.
+    const DELAY_STEP: i64 = 50;
+    /// Milliseconds the audio delay changes by.
+    const AUDIO_DELAY_STEP: i64 = 10;
+
+    /// Creates a [`Player`] for the current video of `queue`, failing if it
+    /// can't be opened.
+    pub fn new(queue: Queue) -> error::Result<(Self, Task<PlayerMessage>)> {
+        let current = queue.current().cloned().unwrap_or_else(|| {
+            // An empty queue has nothing to play, which opening reports.
+            video::Video::from_path(0, PathBuf::new())
+        });
+        let path = current.path.clone();
+        let (video, thumbnails_task) = Self::open(&current)?;
+        let load_id = window::get_oldest().map(PlayerMessage::WindowId);
+
+        let is_fullscreen = false;
+        let settings = Config::load();
+        let mut player = Self {
+            position: video.position().as_secs_f64(),
+            volume: video.volume(),
+            boost: 1.0,
+            speed: video.speed(),
+            show_controls: !is_fullscreen,
+            maximised: false,
+            is_fullscreen,
+            queue,
+            show_queue: false,
+            up_next: None,
+            up_next_cancelled: false,
+            subtitles: vec![],
+            subtitle: None,
+            secondary: None,
+            show_subtitles: true,
+            cue: None,
+            secondary_cue: None,
+            subtitle_font: settings.subtitles.font(),
+            osd: None,
+            toast: None,
+            audio_tracks: vec![],
+            text_tracks: vec![],
+            audio: 0,
+            chapters: vec![],
+            loop_a: None,
+            loop_b: None,
+            clip_in: None,
+            clip_out: None,
+            show_export: false,
+            show_adjustments: false,
+            adjustments: Adjustments::default(),
+            custom_aspect: String::new(),
+            modifiers: keyboard::Modifiers::default(),
+            zooming: false,
+            panning: None,
+            window_size: Size::ZERO,
+            export_format: export::Format::default(),
+            export_width: 480,
+            export_fps: 15,
+            export_job: None,
+            renaming: None,
+            config: VideoConfig::load(&path),
+            settings,
+            keymap: Keymap::load(),
+            is_dragging: false,
+            video,
+            thumbnails: vec![],
+            window_id: None,
+        };
+        player.restore_tracks();
+        player.restore_adjustments();
+        player.set_volume(player.settings.playback.default_volume);
+        audio::set_delay(&player.video.pipeline(), player.config.audio_delay);
+        let crop_task = player.detect_crop();
 
-    pub fn boot() -> (Self, Task<PlayerMessage>) {
-        let path = PathBuf::from("assets/test.mkv");
-        // A better approach would carry only the name and some way to identify the video.
-        let path_ref = path.clone();
-        let mut video =
-            Video::new(&url::Url::from_file_path(path.canonicalize().unwrap()).unwrap()).unwrap();
-        video.set_gamma(1.5);
+        Ok((player, Task::batch([thumbnails_task, load_id, crop_task])))
+    }
+
+    /// Consumes the [`Player`], returning what is left of its queue.
+    pub fn into_queue(self) -> Queue {
+        self.queue
+    }
+
+    fn open(entry: &video::Video) -> error::Result<(Video, Task<PlayerMessage>)> {
+        let url = entry.uri()?;
+        let mut video = Video::new(&url)?;
+        let filters_task = match audio::install(&video.pipeline()) {
+            Ok(()) => Task::none(),
+            Err(err) => Task::done(PlayerMessage::AudioFiltersFailed(err.to_string())),
+        };
+        let tracks_task = Task::run(tracks::changes(&video.pipeline()), |_| {
+            PlayerMessage::TracksChanged
+        });
+
+        // Thumbnails and subtitles beside the video are only read from files.
+        if entry.is_stream() {
+            return Ok((
+                video,
+                Task::batch([filters_task, tracks_task, Self::load_chapters(&url)]),
+            ));
+        }
 
+        let path = entry.path.as_path();
+        let path_ref = path.to_path_buf();
         let thumbnails_task = {
             let duration = video.duration().as_secs_f64();
             let interval = 100u32;
@@ -102,382 +468,2884 @@ impl Player {
                 |res| PlayerMessage::ThumbnailsReady(res.unwrap()),
             )
         };
-        // todo: belongs in main app
-        let load_font = load_fonts().map(PlayerMessage::FontLoad);
-        let load_id = window::get_oldest().map(PlayerMessage::WindowId);
 
-        let tasks = Task::batch(vec![thumbnails_task, load_font, load_id]);
+        let subtitles_task = Self::load_subtitles(subtitles::sidecars(path), false);
+        let chapters_task = Self::load_chapters(&url);
 
-        (Self::new(video, path), tasks)
+        Ok((
+            video,
+            Task::batch([
+                filters_task,
+                tracks_task,
+                thumbnails_task,
+                subtitles_task,
+                chapters_task,
+            ]),
+        ))
     }
 
-    fn new(video: Video, path: PathBuf) -> Self {
-        let is_fullscreen = false;
-        Self {
-            position: video.position().as_secs_f64(),
-            volume: video.volume(),
-            speed: video.speed(),
-            show_controls: !is_fullscreen,
-            maximised: false,
-            is_fullscreen,
-            has_previous: false,
-            has_next: false,
-            is_dragging: false,
-            video,
-            path,
-            thumbnails: vec![],
-            window_id: None,
+    /// Reads the chapters of the video at `url` in the background.
+    fn load_chapters(url: &url::Url) -> Task<PlayerMessage> {
+        let uri = url.to_string();
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || chapters::chapters(&uri)),
+            |res| match res {
+                Ok(Ok(chapters)) => PlayerMessage::ChaptersLoaded(chapters),
+                Ok(Err(err)) => {
+                    eprintln!("Error reading chapters: \n{err}");
+                    PlayerMessage::ChaptersLoaded(vec![])
+                }
+                Err(_) => PlayerMessage::ChaptersLoaded(vec![]),
+            },
+        )
+    }
+
+    /// Loads and parses subtitle files in the background.
+    /// `select` shows the first of them once loaded.
+    fn load_subtitles(paths: Vec<PathBuf>, select: bool) -> Task<PlayerMessage> {
+        if paths.is_empty() {
+            return Task::none();
         }
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || {
+                paths
+                    .into_iter()
+                    .filter_map(|path| match Subtitles::load(&path) {
+                        Ok(subtitles) => Some(subtitles),
+                        Err(err) => {
+                            eprintln!("{err}");
+                            None
+                        }
+                    })
+                    .collect()
+            }),
+            move |res| PlayerMessage::SubtitlesLoaded(res.unwrap_or_default(), select),
+        )
     }
 
-    pub fn update(&mut self, message: PlayerMessage) -> Task<PlayerMessage> {
-        match message {
-            PlayerMessage::WindowId(id) => {
-                self.window_id = id;
-                Task::none()
+    /// Refreshes the subtitle cues for the current position.
+    /// Embedded cues arrive through [`PlayerMessage::SubtitleText`] instead.
+    fn update_cue(&mut self) {
+        let position = Duration::from_secs_f64(self.position.max(0.0));
+        let delay = Duration::from_millis(self.config.subtitle_delay.unsigned_abs());
+
+        // A positive delay shows cues later.
+        let position = if self.config.subtitle_delay >= 0 {
+            position.saturating_sub(delay)
+        } else {
+            position + delay
+        };
+
+        self.cue = self.cue_at(self.subtitle, &self.cue, position);
+        self.secondary_cue = self.cue_at(self.secondary, &self.secondary_cue, position);
+    }
+
+    fn cue_at(
+        &self,
+        source: Option<SubtitleSource>,
+        current: &Option<String>,
+        position: Duration,
+    ) -> Option<String> {
+        match source {
+            Some(SubtitleSource::External(index)) => self
+                .subtitles
+                .get(index)
+                .and_then(|subtitles| subtitles.at(position)),
+            Some(SubtitleSource::Embedded(_)) => current.clone(),
+            None => None,
+        }
+    }
+
+    fn select_subtitles(&mut self, source: Option<SubtitleSource>) {
+        // The pipeline only decodes one embedded stream at a time.
+        if self.secondary == source
+            || source.is_some_and(|source| source.is_embedded())
+                && self.secondary.is_some_and(|source| source.is_embedded())
+        {
+            self.secondary = None;
+        }
+
+        self.subtitle = source;
+        self.show_subtitles = source.is_some();
+        self.sync_subtitles();
+    }
+
+    fn select_secondary(&mut self, source: Option<SubtitleSource>) {
+        if self.subtitle == source
+            || source.is_some_and(|source| source.is_embedded())
+                && self.subtitle.is_some_and(|source| source.is_embedded())
+        {
+            self.subtitle = None;
+        }
+
+        self.secondary = source;
+        self.show_subtitles = source.is_some() || self.subtitle.is_some();
+        self.sync_subtitles();
+    }
+
+    /// Points the pipeline at the embedded stream in use, if any.
+    fn sync_subtitles(&mut self) {
+        let pipeline = self.video.pipeline();
+        let embedded = [self.subtitle, self.secondary]
+            .into_iter()
+            .flatten()
+            .find_map(|source| match source {
+                SubtitleSource::Embedded(index) => Some(index),
+                SubtitleSource::External(_) => None,
+            });
+
+        match embedded {
+            Some(index) => tracks::select(&pipeline, TrackKind::Text, index),
+            None => tracks::set_text_enabled(&pipeline, false),
+        }
+        tracks::set_text_offset(&pipeline, self.config.subtitle_delay);
+
+        self.cue = None;
+        self.secondary_cue = None;
+        self.update_cue();
+    }
+
+    /// Resolves a remembered choice against the current tracks.
+    fn source(&self, choice: &SubtitleChoice) -> Option<SubtitleSource> {
+        match choice {
+            SubtitleChoice::Off => None,
+            SubtitleChoice::Embedded(index) => {
+                (*index < self.text_tracks.len() as i32).then_some(SubtitleSource::Embedded(*index))
             }
-            PlayerMessage::Resize((id, size)) => {
-                if Some(id) == self.window_id {
-                    let maximised = Size::new(1000., 1000.);
-                    self.maximised =
-                        size.width >= maximised.width && size.height >= maximised.height;
-                    self.show_controls = !self.maximised;
-                }
-                Task::none()
+            SubtitleChoice::External(path) => self
+                .subtitles
+                .iter()
+                .position(|subtitles| &subtitles.path == path)
+                .map(SubtitleSource::External),
+        }
+    }
+
+    fn choice(&self, source: Option<SubtitleSource>) -> SubtitleChoice {
+        match source {
+            None => SubtitleChoice::Off,
+            Some(SubtitleSource::Embedded(index)) => SubtitleChoice::Embedded(index),
+            Some(SubtitleSource::External(index)) => match self.subtitles.get(index) {
+                Some(subtitles) => SubtitleChoice::External(subtitles.path.clone()),
+                None => SubtitleChoice::Off,
+            },
+        }
+    }
+
+    fn seek_to(&mut self, position: Duration) {
+        if let Err(err) = self.video.seek(position, false) {
+            eprintln!("{err:?}");
+            return;
+        }
+
+        self.position = position.as_secs_f64();
+        self.update_cue();
+    }
+
+    fn seek_chapter(&mut self, index: usize) {
+        let Some(chapter) = self.chapters.get(index) else {
+            return;
+        };
+
+        let label = chapter.label(index);
+        self.seek_to(chapter.start);
+        self.show_osd(label);
+    }
+
+    /// Moves exactly one frame. Forward steps use step events, which
+    /// keyframe seeks cannot do, while backward ones need an accurate seek.
+    fn step_frame(&mut self, forward: bool) {
+        let framerate = self.video.framerate();
+        if framerate <= 0.0 {
+            return;
+        }
+
+        self.video.set_paused(true);
+        self.is_dragging = false;
+
+        let frame = (self.position * framerate).round() as i64;
+        let frame = if forward {
+            let step = gst::event::Step::new(gst::format::Buffers::from_u64(1), 1.0, true, false);
+
+            if !self.video.pipeline().send_event(step) {
+                return;
             }
-            PlayerMessage::None => Task::none(),
-            PlayerMessage::FontLoad(Err(error)) => {
-                eprintln!("{error:?}");
-                Task::none()
+            frame + 1
+        } else {
+            let frame = (frame - 1).max(0);
+            let position = Duration::from_secs_f64(frame as f64 / framerate);
+
+            if let Err(err) = self.video.seek(position, true) {
+                eprintln!("{err:?}");
+                return;
             }
-            PlayerMessage::FontLoad(_) => Task::none(),
-            PlayerMessage::EndOfStream => {
-                todo!("Send message to main with some video stats");
+            frame
+        };
+
+        self.position = frame as f64 / framerate;
+        self.update_cue();
+
+        let millis = (self.position * 1000.0).round() as u64;
+        let secs = millis / 1000;
+        let time = format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+            millis % 1000
+        );
+        self.show_osd(t!("player-frame", frame = frame, time = time));
+    }
+
+    fn update_loop(&mut self, message: LoopMessage) {
+        let duration = self.video.duration().as_secs_f64();
+
+        match message {
+            LoopMessage::Cycle => match (self.loop_a, self.loop_b) {
+                (None, _) => return self.update_loop(LoopMessage::SetA),
+                (Some(_), None) => return self.update_loop(LoopMessage::SetB),
+                (Some(_), Some(_)) => return self.update_loop(LoopMessage::Clear),
+            },
+            LoopMessage::SetA => {
+                self.loop_a = Some(self.position);
+                self.loop_b = self.loop_b.filter(|b| *b > self.position);
             }
-            PlayerMessage::NewFrame => {
-                if !self.is_dragging {
-                    self.position = self.video.position().as_secs_f64();
+            LoopMessage::SetB => match self.loop_a {
+                Some(a) if a < self.position => self.loop_b = Some(self.position),
+                // B has to come after A, so the markers are swapped.
+                Some(a) => {
+                    self.loop_a = Some(self.position);
+                    self.loop_b = Some(a);
                 }
-                Task::none()
+                None => {
+                    self.loop_a = Some(0.0);
+                    self.loop_b = Some(self.position);
+                }
+            },
+            LoopMessage::Clear => {
+                self.loop_a = None;
+                self.loop_b = None;
+                self.show_osd(t!("player-loop-cleared"));
+                return;
             }
-            PlayerMessage::SeekRelease => {
-                self.is_dragging = false;
-                self.video
-                    .seek(Duration::from_secs_f64(self.position.max(0.0)), false)
-                    .unwrap();
-
-                self.video.set_paused(false);
-                Task::none()
+            LoopMessage::NudgeA(amount) => {
+                let Some(a) = self.loop_a else {
+                    return;
+                };
+                let limit = self.loop_b.unwrap_or(duration) - Self::LOOP_NUDGE;
+                self.loop_a = Some((a + amount).clamp(0.0, limit.max(0.0)));
             }
-            PlayerMessage::Seek(pos) => {
-                self.position = pos.max(0.0);
-                self.is_dragging = true;
-                self.video.set_paused(true);
-                Task::none()
+            LoopMessage::NudgeB(amount) => {
+                let Some(b) = self.loop_b else {
+                    return;
+                };
+                let limit = self.loop_a.unwrap_or_default() + Self::LOOP_NUDGE;
+                self.loop_b = Some((b + amount).clamp(limit, duration.max(limit)));
             }
-            PlayerMessage::TogglePlay => {
-                self.video.set_paused(!self.video.paused());
-                Task::none()
+        }
+
+        let osd = match (self.loop_a, self.loop_b) {
+            (Some(a), Some(b)) => t!("player-loop", start = timestamp(a), end = timestamp(b)),
+            (Some(a), None) => t!("player-loop", start = timestamp(a), end = "…"),
+            _ => return,
+        };
+        self.show_osd(osd);
+    }
+
+    fn show_toast(&mut self, message: impl Into<String>, screenshot: Option<PathBuf>) {
+        self.toast = Some(Toast {
+            message: message.into(),
+            screenshot,
+            shown: Instant::now(),
+        });
+    }
+
+    fn update_bookmarks(&mut self, message: BookmarkMessage) {
+        match message {
+            BookmarkMessage::Add => {
+                let name = t!("player-bookmark", number = self.config.bookmarks.len() + 1);
+                self.show_osd(format!("{name}: {}", timestamp(self.position)));
+
+                let index = self
+                    .config
+                    .bookmarks
+                    .partition_point(|bookmark| bookmark.position <= self.position);
+                self.config.bookmarks.insert(
+                    index,
+                    Bookmark {
+                        name,
+                        position: self.position,
+                    },
+                );
             }
-            PlayerMessage::ThumbnailsReady(Err(_err)) => {
-                todo!("Set up error toasts here");
+            BookmarkMessage::Jump(index) => {
+                if let Some(bookmark) = self.config.bookmarks.get(index) {
+                    let name = bookmark.name.clone();
+                    self.seek_to(Duration::from_secs_f64(bookmark.position));
+                    self.show_osd(name);
+                }
+                return;
             }
-            PlayerMessage::ThumbnailsReady(Ok(thumbnails)) => {
-                self.thumbnails = thumbnails;
-                Task::none()
+            BookmarkMessage::StartRename(index) => {
+                self.renaming = self
+                    .config
+                    .bookmarks
+                    .get(index)
+                    .map(|bookmark| (index, bookmark.name.clone()));
+                return;
             }
-            PlayerMessage::ChangeVolume(volume) => {
-                self.volume = volume;
-                self.video.set_volume(volume);
-                Task::none()
+            BookmarkMessage::Rename(name) => {
+                if let Some((_, renamed)) = self.renaming.as_mut() {
+                    *renamed = name;
+                }
+                return;
             }
-            PlayerMessage::ToggleMute => {
-                let mute = !self.video.muted();
-                self.video.set_muted(mute);
+            BookmarkMessage::FinishRename => {
+                let Some((index, name)) = self.renaming.take() else {
+                    return;
+                };
 
-                if mute {
-                    self.volume = 0.0
-                } else {
-                    self.volume = self.video.volume()
+                match self.config.bookmarks.get_mut(index) {
+                    Some(bookmark) if !name.trim().is_empty() => {
+                        bookmark.name = name.trim().to_owned()
+                    }
+                    _ => return,
                 }
-                Task::none()
             }
-            PlayerMessage::SeekBack(shift) => {
-                self.is_dragging = false;
-                let mult = if shift { 2.0 } else { 1.0 };
-                self.position = (self.position - (self.seek_amount() * mult)).max(0.0);
-                self.video
-                    .seek(Duration::from_secs_f64(self.position), false)
-                    .unwrap();
-                Task::none()
-            }
-            PlayerMessage::SeekFront(shift) => {
-                self.is_dragging = false;
-                let duration = self.video.duration().as_secs_f64();
-                let mult = if shift { 2.0 } else { 1.0 };
-                self.position = (self.position + (self.seek_amount() * mult)).min(duration);
-                self.video
-                    .seek(Duration::from_secs_f64(self.position), false)
-                    .unwrap();
-                Task::none()
+            BookmarkMessage::Delete(index) => {
+                if index < self.config.bookmarks.len() {
+                    self.config.bookmarks.remove(index);
+                }
+                self.renaming = None;
             }
-            PlayerMessage::IncrVolume => {
-                self.volume = (self.volume + self.volume_amount()).min(1.0);
-                self.video.set_volume(self.volume);
-                Task::none()
+        }
+
+        if let Some(video) = self.queue.current_mut() {
+            video.bookmarks = self.config.bookmarks.clone();
+        }
+        self.save_config();
+    }
+
+    /// Applies the adjustments saved for the current video, or the global ones.
+    fn restore_adjustments(&mut self) {
+        self.adjustments = self.config.adjustments.unwrap_or(self.settings.adjustments);
+        self.apply_adjustments();
+    }
+
+    fn apply_adjustments(&mut self) {
+        adjustments::apply(&self.video.pipeline(), &self.adjustments);
+        self.video.set_gamma(self.adjustments.gamma);
+    }
+
+    fn update_adjustments(&mut self, message: AdjustMessage) {
+        match message {
+            AdjustMessage::Set(adjustments) => {
+                self.adjustments = adjustments;
+                self.apply_adjustments();
             }
-            PlayerMessage::DecrVolume => {
-                self.volume = (self.volume - self.volume_amount()).max(0.0);
-                self.video.set_volume(self.volume);
-                Task::none()
+            AdjustMessage::Reset => {
+                self.adjustments = Adjustments::default();
+                self.apply_adjustments();
             }
-            PlayerMessage::IncrSpeed => {
-                self.speed += self.speed_amount();
-                self.video.set_speed(self.speed).unwrap();
-                Task::none()
+            AdjustMessage::SaveGlobally => {
+                self.settings.adjustments = self.adjustments;
+                if let Err(err) = self.settings.save() {
+                    eprintln!("Error saving settings: \n{err}");
+                }
+
+                // The video follows the global adjustments from now on.
+                if self.config.adjustments.take().is_some() {
+                    self.save_config();
+                }
+                self.show_osd(t!("player-adjustments-saved-all"));
             }
-            PlayerMessage::DecrSpeed => {
-                self.speed -= self.speed_amount();
-                self.video.set_speed(self.speed).unwrap();
-                Task::none()
+            AdjustMessage::SaveForVideo => {
+                self.config.adjustments = Some(self.adjustments);
+                self.save_config();
+                self.show_osd(t!("player-adjustments-saved-video"));
             }
-            PlayerMessage::ResetSpeed => {
-                self.speed = 1.0;
-                self.video.set_speed(self.speed).unwrap();
-                Task::none()
+        }
+    }
+
+    /// Sets the volume, boosting it through the audio filter beyond full.
+    fn set_volume(&mut self, volume: f64) {
+        self.volume = volume;
+        self.boost = volume.max(1.0);
+        self.video.set_volume(volume.min(1.0));
+        self.apply_audio();
+    }
+
+    fn apply_audio(&self) {
+        let pipeline = self.video.pipeline();
+        // Tags of the stream come first, then the library's own measurement.
+        let replay_gain = audio::replay_gain(&pipeline, self.audio)
+            .or_else(|| self.config.loudness.map(|loudness| loudness.gain()));
+
+        audio::apply(&pipeline, &self.settings.audio, self.boost, replay_gain);
+    }
+
+    fn update_audio(&mut self, message: AudioMessage) {
+        let filters = &mut self.settings.audio;
+
+        match message {
+            AudioMessage::NightMode(night_mode) => filters.night_mode = night_mode,
+            AudioMessage::Normalize(normalize) => filters.normalize = normalize,
+            AudioMessage::Preset(preset) => {
+                filters.preset = preset;
+                if let Some(bands) = preset.bands() {
+                    filters.bands = bands;
+                }
             }
-            PlayerMessage::CursorExit => {
-                if self.is_fullscreen || self.maximised {
-                    self.show_controls = false;
+            AudioMessage::Band(index, gain) => {
+                filters.preset = EqualizerPreset::Custom;
+                if let Some(band) = filters.bands.get_mut(index) {
+                    *band = gain;
                 }
-                Task::none()
+                self.apply_audio();
+                return;
             }
-            PlayerMessage::CursorEnter => {
-                self.show_controls = true;
-                Task::none()
+            AudioMessage::Save => {}
+        }
+
+        self.apply_audio();
+        if let Err(err) = self.settings.save() {
+            eprintln!("Error saving settings: \n{err}");
+        }
+    }
+
+    /// The framing in effect, with black bars cropped away when the video
+    /// has no crop of its own.
+    fn framing(&self) -> Framing {
+        let mut framing = self.config.framing;
+
+        if self.settings.auto_crop && framing.crop == Crop::default() {
+            framing.crop = self.config.detected_crop.unwrap_or_default();
+        }
+
+        framing
+    }
+
+    /// Looks for black bars in the current video, unless already found.
+    fn detect_crop(&self) -> Task<PlayerMessage> {
+        if !self.settings.auto_crop || self.config.detected_crop.is_some() {
+            return Task::none();
+        }
+
+        let Some(path) = self
+            .queue
+            .current()
+            .filter(|video| !video.is_stream())
+            .map(|video| video.path.clone())
+        else {
+            return Task::none();
+        };
+        let (width, height) = self.video.size();
+
+        Task::perform(
+            tokio::task::spawn_blocking({
+                let path = path.clone();
+                move || letterbox::detect(path, width, height)
+            }),
+            move |res| PlayerMessage::CropDetected(path, res.ok()),
+        )
+    }
+
+    fn set_detected_crop(&mut self, path: PathBuf, crop: Crop) {
+        let current = self.queue.current().map(|video| &video.path);
+
+        if current == Some(&path) {
+            self.config.detected_crop = Some(crop);
+            self.save_config();
+            return;
+        }
+
+        // The queue moved on while the video was looked at.
+        if let Err(err) = VideoConfig::update(&path, |config| config.detected_crop = Some(crop)) {
+            eprintln!("Error saving video settings: \n{err}");
+        }
+    }
+
+    /// The size of the video in pixels.
+    fn source_size(&self) -> Size {
+        let (width, height) = self.video.size();
+        Size::new(width as f32, height as f32)
+    }
+
+    fn update_framing(&mut self, message: FrameMessage) {
+        let framing = &mut self.config.framing;
+
+        match message {
+            FrameMessage::Aspect(aspect) => {
+                framing.aspect = aspect;
+                self.custom_aspect.clear();
             }
-            PlayerMessage::ToggleFullscreen => {
-                self.show_controls = self.is_fullscreen;
-                self.is_fullscreen = !self.is_fullscreen;
-                let fullscreen = self.is_fullscreen;
-                iced::window::get_latest()
-                    .and_then(move |id| {
-                        iced::window::set_mode::<()>(
-                            id,
-                            if fullscreen {
-                                iced::window::Mode::Fullscreen
-                            } else {
-                                iced::window::Mode::Windowed
-                            },
-                        )
-                    })
-                    .discard()
+            FrameMessage::CustomAspect(input) => {
+                if let Some(aspect) = AspectRatio::parse(&input) {
+                    framing.aspect = aspect;
+                }
+                self.custom_aspect = input;
+                // Saved once the edit is submitted.
+                return;
             }
-            PlayerMessage::ExitFullscreen => {
-                self.show_controls = true;
-                self.is_fullscreen = false;
-                let fullscreen = self.is_fullscreen;
-                iced::window::get_latest()
-                    .and_then(move |id| {
-                        iced::window::set_mode::<()>(
-                            id,
-                            if fullscreen {
-                                iced::window::Mode::Fullscreen
-                            } else {
-                                iced::window::Mode::Windowed
-                            },
-                        )
-                    })
-                    .discard()
+            FrameMessage::Fit(fit) => {
+                framing.fit = fit;
+                framing.pan_x = 0.0;
+                framing.pan_y = 0.0;
             }
-            PlayerMessage::PreviousScreen => {
-                todo!("send message to main with video stats");
+            FrameMessage::Wheel(lines) => {
+                if !self.modifiers.command() || lines == 0.0 {
+                    return;
+                }
+
+                let (min, max) = Framing::ZOOM.into_inner();
+                framing.zoom = (framing.zoom * 1.1f32.powf(lines)).clamp(min, max);
+                let zoom = framing.zoom;
+                self.show_osd(t!("player-zoom", zoom = format!("{:.0}", zoom * 100.0)));
+
+                // Saved once the command key is let go of.
+                self.zooming = true;
+                return;
             }
-            PlayerMessage::AddCollection => Task::none(),
-            PlayerMessage::Config => Task::none(),
-            PlayerMessage::ToggleSubtitles => Task::none(),
-            PlayerMessage::PlayNext => Task::none(),
-            PlayerMessage::PlayPrevious => Task::none(),
-            PlayerMessage::Favorite => Task::none(),
-            PlayerMessage::Comment => Task::none(),
+            FrameMessage::ResetZoom => {
+                framing.zoom = 1.0;
+                framing.pan_x = 0.0;
+                framing.pan_y = 0.0;
+            }
+            FrameMessage::Crop(crop) => {
+                framing.crop = crop;
+                // Saved once the slider is let go of.
+                return;
+            }
+            FrameMessage::ResetCrop => framing.crop = Crop::default(),
+            FrameMessage::AutoCrop(auto_crop) => {
+                self.settings.auto_crop = auto_crop;
+                if let Err(err) = self.settings.save() {
+                    eprintln!("Error saving settings: \n{err}");
+                }
+                self.show_osd(if auto_crop {
+                    t!("player-auto-crop-on")
+                } else {
+                    t!("player-auto-crop-off")
+                });
+                return;
+            }
+            FrameMessage::Modifiers(modifiers) => {
+                self.modifiers = modifiers;
+
+                if !self.zooming || modifiers.command() {
+                    return;
+                }
+                self.zooming = false;
+            }
+            FrameMessage::PanStart => {
+                self.panning = Some(None);
+                return;
+            }
+            FrameMessage::Pan(cursor) => {
+                let Some(last) = self.panning.replace(Some(cursor)).flatten() else {
+                    return;
+                };
+
+                let overflow =
+                    framing::overflow(&self.framing(), self.source_size(), self.window_size);
+                let mut framing = self.config.framing;
+
+                if overflow.x > 0.0 {
+                    framing.pan_x =
+                        (framing.pan_x + (cursor.x - last.x) * 2.0 / overflow.x).clamp(-1.0, 1.0);
+                }
+                if overflow.y > 0.0 {
+                    framing.pan_y =
+                        (framing.pan_y + (cursor.y - last.y) * 2.0 / overflow.y).clamp(-1.0, 1.0);
+                }
+                self.config.framing = framing;
+
+                // Saved once the drag ends.
+                return;
+            }
+            FrameMessage::PanEnd => {
+                self.panning = None;
+            }
+            FrameMessage::Save => {}
         }
+
+        self.save_config();
     }
 
-    pub fn subscriptions(&self) -> Subscription<PlayerMessage> {
-        window::resize_events().map(PlayerMessage::Resize)
+    /// The section to export, defaulting to the whole video.
+    fn clip(&self) -> (f64, f64) {
+        let start = self.clip_in.unwrap_or_default();
+        let end = self
+            .clip_out
+            .unwrap_or_else(|| self.video.duration().as_secs_f64());
+
+        (start, end)
     }
 
-    fn name(&self) -> &str {
-        self.path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .unwrap_or_default()
+    fn update_export(&mut self, message: ExportMessage) -> Task<PlayerMessage> {
+        match message {
+            ExportMessage::Toggle => self.show_export = !self.show_export,
+            ExportMessage::SetIn => {
+                self.clip_in = Some(self.position);
+                self.clip_out = self.clip_out.filter(|out| *out > self.position);
+                self.show_osd(t!("player-clip-in", time = timestamp(self.position)));
+            }
+            ExportMessage::SetOut => {
+                self.clip_out = Some(self.position);
+                self.clip_in = self.clip_in.filter(|start| *start < self.position);
+                self.show_osd(t!("player-clip-out", time = timestamp(self.position)));
+            }
+            ExportMessage::Clear => {
+                self.clip_in = None;
+                self.clip_out = None;
+            }
+            ExportMessage::Format(format) => self.export_format = format,
+            ExportMessage::Width(width) => self.export_width = width,
+            ExportMessage::Fps(fps) => self.export_fps = fps,
+            ExportMessage::Start => {
+                let (start, end) = self.clip();
+                let extension = self.export_format.extension();
+                let name = format!(
+                    "{}_{}-{}.{extension}",
+                    self.name(),
+                    timestamp(start).replace(':', "-"),
+                    timestamp(end).replace(':', "-"),
+                );
+
+                return Task::perform(
+                    rfd::AsyncFileDialog::new()
+                        .add_filter(extension, &[extension])
+                        .set_file_name(name)
+                        .save_file(),
+                    |file| {
+                        PlayerMessage::Export(ExportMessage::Destination(
+                            file.map(|file| file.path().to_owned()),
+                        ))
+                    },
+                );
+            }
+            ExportMessage::Destination(None) => {}
+            ExportMessage::Destination(Some(destination)) => {
+                let Some(source) = self.queue.current().and_then(|video| video.uri().ok()) else {
+                    return Task::none();
+                };
+
+                let (start, end) = self.clip();
+                let job = export::Job {
+                    source,
+                    destination,
+                    format: self.export_format,
+                    start: Duration::from_secs_f64(start),
+                    end: Duration::from_secs_f64(end),
+                    width: self.export_width,
+                    fps: self.export_fps,
+                    has_audio: !self.audio_tracks.is_empty(),
+                };
+
+                let cancel = Arc::new(AtomicBool::new(false));
+                self.export_job = Some(ExportJob {
+                    progress: 0.0,
+                    cancel: cancel.clone(),
+                });
+
+                return Task::run(export::run(job, cancel), |progress| {
+                    PlayerMessage::Export(ExportMessage::Progress(progress))
+                });
+            }
+            ExportMessage::Progress(export::Progress::Running(progress)) => {
+                if let Some(job) = self.export_job.as_mut() {
+                    job.progress = progress;
+                }
+            }
+            ExportMessage::Progress(export::Progress::Finished(Ok(path))) => {
+                self.export_job = None;
+                let path = path.display().to_string();
+                self.show_toast(t!("player-exported", path = path), None);
+            }
+            ExportMessage::Progress(export::Progress::Finished(Err(err))) => {
+                self.export_job = None;
+                self.show_toast(t!("player-export-failed", error = err.to_string()), None);
+            }
+            ExportMessage::Progress(export::Progress::Cancelled) => {
+                self.export_job = None;
+                self.show_toast(t!("player-export-cancelled"), None);
+            }
+            ExportMessage::Cancel => {
+                if let Some(job) = self.export_job.as_ref() {
+                    job.cancel.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+
+        Task::none()
     }
 
-    fn volume_amount(&self) -> f64 {
-        0.05
+    /// Saves the current frame in the background.
+    fn screenshot(&mut self) -> Task<PlayerMessage> {
+        let Some(frame) = screenshot::frame(&self.video.pipeline()) else {
+            self.show_toast(t!("player-no-frame"), None);
+            return Task::none();
+        };
+
+        let settings = &self.settings.screenshots;
+        let position = Duration::from_secs_f64(self.position.max(0.0));
+
+        let path = match settings.path(self.name(), position) {
+            Ok(path) => path,
+            Err(err) => {
+                self.show_toast(
+                    t!("player-screenshot-failed", error = err.to_string()),
+                    None,
+                );
+                return Task::none();
+            }
+        };
+
+        let subtitles = self
+            .cue
+            .clone()
+            .filter(|_| settings.burn_subtitles && self.show_subtitles);
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || {
+                screenshot::save(&frame, subtitles.as_deref(), &path)
+                    .map(|_| path)
+                    .map_err(|err| err.to_string())
+            }),
+            |res| PlayerMessage::ScreenshotSaved(res.unwrap_or_else(|err| Err(err.to_string()))),
+        )
+    }
+
+    /// Moves playback to `position`, such as a bookmark picked in the library.
+    pub fn start_at(&mut self, position: Duration) {
+        self.seek_to(position);
+    }
+
+    /// The length of the current video, zero when unknown such as for a live
+    /// stream.
+    pub fn duration(&self) -> Duration {
+        self.video.duration()
+    }
+
+    /// Plays at `speed` times the normal rate.
+    pub fn set_speed(&mut self, speed: f64) {
+        if let Err(err) = self.video.set_speed(speed) {
+            eprintln!("Error changing speed: \n{err:?}");
+            return;
+        }
+
+        self.speed = speed;
+    }
+
+    /// The current video, as played from the queue.
+    pub fn current_video(&self) -> Option<&video::Video> {
+        self.queue.current()
+    }
+
+    /// Where the end credits of the current video start, as marked or named
+    /// by a chapter.
+    fn credits(&self) -> Option<Duration> {
+        self.config
+            .credits
+            .map(Duration::from_millis)
+            .or_else(|| chapters::credits(&self.chapters, self.video.duration()))
+    }
+
+    fn show_osd(&mut self, message: impl Into<String>) {
+        self.osd = Some((message.into(), Instant::now()));
+    }
+
+    /// Reads the streams of the current video and applies the remembered
+    /// choices, falling back to the language rules.
+    fn restore_tracks(&mut self) {
+        let pipeline = self.video.pipeline();
+        self.audio_tracks = tracks::tracks(&pipeline, TrackKind::Audio);
+        self.text_tracks = tracks::tracks(&pipeline, TrackKind::Text);
+
+        let audio = self
+            .config
+            .audio
+            .filter(|audio| *audio < self.audio_tracks.len() as i32)
+            .or_else(|| self.auto_audio());
+
+        if let Some(audio) = audio {
+            tracks::select(&pipeline, TrackKind::Audio, audio);
+        }
+        self.audio = tracks::current(&pipeline, TrackKind::Audio);
+
+        // Sidecar files are restored once they have loaded.
+        let source = match self.config.subtitles.as_ref() {
+            Some(choice) => self.source(choice),
+            None => self.auto_subtitles(),
+        };
+        let secondary = self
+            .config
+            .secondary_subtitles
+            .as_ref()
+            .and_then(|choice| self.source(choice));
+
+        self.subtitle = source;
+        self.secondary = secondary;
+        self.show_subtitles = source.is_some() || secondary.is_some();
+        self.sync_subtitles();
+    }
+
+    /// The language rules for the current video.
+    fn languages(&self) -> &Languages {
+        self.settings
+            .languages(self.queue.current().and_then(|video| video.series.as_ref()))
+    }
+
+    /// Picks the audio stream in the most preferred language.
+    fn auto_audio(&self) -> Option<i32> {
+        self.languages()
+            .audio
+            .iter()
+            .find_map(|language| {
+                self.audio_tracks
+                    .iter()
+                    .find(|track| track.speaks(language))
+            })
+            .map(|track| track.index)
+    }
+
+    /// Picks subtitles following the language rules. Sidecar files win over
+    /// embedded streams in the same language.
+    fn auto_subtitles(&self) -> Option<SubtitleSource> {
+        let languages = self.languages();
+
+        let audio_matches = self
+            .audio_tracks
+            .iter()
+            .find(|track| track.index == self.audio)
+            .is_some_and(|track| {
+                languages
+                    .subtitles
+                    .iter()
+                    .any(|language| track.speaks(language))
+            });
+
+        let full = match languages.subtitle_mode {
+            SubtitleMode::Always => true,
+            SubtitleMode::Foreign => !audio_matches,
+            SubtitleMode::ForcedOnly => false,
+            SubtitleMode::Off => return None,
+        };
+
+        let find = |language: Option<&str>| {
+            let external = self
+                .subtitles
+                .iter()
+                .position(|subtitles| {
+                    language.is_none_or(|language| subtitles.speaks(language))
+                        && subtitles.is_forced() != full
+                })
+                .map(SubtitleSource::External);
+
+            external.or_else(|| {
+                self.text_tracks
+                    .iter()
+                    .find(|track| {
+                        language.is_none_or(|language| track.speaks(language))
+                            && track.is_forced() != full
+                    })
+                    .map(|track| SubtitleSource::Embedded(track.index))
+            })
+        };
+
+        if languages.subtitles.is_empty() {
+            // Without preferred languages any forced track will do, and full
+            // subtitles keep the first sidecar or the selected embedded track.
+            return if !full {
+                find(None)
+            } else if !self.subtitles.is_empty() {
+                Some(SubtitleSource::External(0))
+            } else if !self.text_tracks.is_empty() {
+                let current = tracks::current(&self.video.pipeline(), TrackKind::Text);
+                Some(SubtitleSource::Embedded(current.max(0)))
+            } else {
+                None
+            };
+        }
+
+        languages
+            .subtitles
+            .iter()
+            .find_map(|language| find(Some(language)))
+    }
+
+    /// Stores the current track choices for the current video.
+    fn remember_tracks(&mut self) {
+        self.config.audio = Some(self.audio);
+        self.config.subtitles = Some(self.choice(self.subtitle));
+        self.config.secondary_subtitles = Some(self.choice(self.secondary));
+        self.save_config();
+    }
+
+    fn save_config(&self) {
+        let Some(video) = self.queue.current() else {
+            return;
+        };
+
+        // The library may have measured the video since it was opened.
+        let result = VideoConfig::update(&video.path, |stored| {
+            *stored = VideoConfig {
+                loudness: stored.loudness,
+                palette: stored.palette,
+                ..self.config.clone()
+            };
+        });
+
+        if let Err(err) = result {
+            eprintln!("Error saving video settings: \n{err}");
+        }
+    }
+
+    /// Switches playback to the current video of the queue, keeping the
+    /// volume and speed of the previous one.
+    fn play_current(&mut self) -> Task<PlayerMessage> {
+        let Some(current) = self.queue.current().cloned() else {
+            return Task::none();
+        };
+        let path = current.path.clone();
+
+        let muted = self.video.muted();
+        let (video, thumbnails_task) = match Self::open(&current) {
+            Ok(opened) => opened,
+            Err(err) => {
+                let error = err.to_string();
+                self.show_toast(
+                    t!("player-open-failed", name = current.name, error = error),
+                    None,
+                );
+                return Task::none();
+            }
+        };
+
+        self.video = video;
+        self.video.set_volume(self.video_volume().min(1.0));
+        self.video.set_muted(muted);
+        if let Err(err) = self.video.set_speed(self.speed) {
+            eprintln!("{err:?}");
+        }
+
+        self.position = 0.0;
+        self.is_dragging = false;
+        self.thumbnails.clear();
+        self.up_next = None;
+        self.up_next_cancelled = false;
+        self.subtitles.clear();
+        self.chapters.clear();
+        self.loop_a = None;
+        self.loop_b = None;
+        self.clip_in = None;
+        self.clip_out = None;
+        self.renaming = None;
+        self.config = VideoConfig::load(&path);
+        self.restore_tracks();
+        self.restore_adjustments();
+        self.apply_audio();
+        audio::set_delay(&self.video.pipeline(), self.config.audio_delay);
+
+        Task::batch([thumbnails_task, self.detect_crop()])
+    }
+
+    /// Shows the up next card if there is a video to play after this one.
+    fn show_up_next(&mut self) {
+        if self.up_next.is_some() || self.up_next_cancelled {
+            return;
+        }
+
+        // Repeating the video replays it at the end instead of offering it.
+        if self.repeats_current() {
+            return;
+        }
+
+        let Some(next) = self.queue.on_end() else {
+            return;
+        };
+
+        self.up_next = Some(UpNext {
+            remaining: Self::COUNTDOWN,
+            poster: next.poster.as_ref().map(image::Handle::from_path),
+        });
+    }
+
+    /// Whether the current video starts over once it ends, rather than
+    /// moving on.
+    fn repeats_current(&self) -> bool {
+        let current = self.queue.current().map(|video| video.id);
+        current.is_some() && self.queue.on_end().map(|video| video.id) == current
+    }
+
+    /// Starts the current video over from the beginning.
+    fn replay(&mut self) {
+        self.up_next = None;
+        self.seek_to(Duration::ZERO);
+        self.video.set_paused(false);
+    }
+
+    /// The volume to hand over to a newly opened video.
+    fn video_volume(&self) -> f64 {
+        if self.video.muted() {
+            self.video.volume()
+        } else {
+            self.volume
+        }
+    }
+
+    /// The message a chord is bound to in the player.
+    pub fn resolve(&self, chord: &Chord) -> Option<PlayerMessage> {
+        let message = match self.keymap.action(chord, Scope::Player)? {
+            Action::TogglePlay => PlayerMessage::TogglePlay,
+            Action::ToggleFullscreen => PlayerMessage::ToggleFullscreen,
+            Action::ExitFullscreen => PlayerMessage::ExitFullscreen,
+            Action::SeekBack => PlayerMessage::SeekBack(false),
+            Action::SeekBackFar => PlayerMessage::SeekBack(true),
+            Action::SeekForward => PlayerMessage::SeekFront(false),
+            Action::SeekForwardFar => PlayerMessage::SeekFront(true),
+            Action::VolumeUp => PlayerMessage::IncrVolume,
+            Action::VolumeDown => PlayerMessage::DecrVolume,
+            Action::ToggleMute => PlayerMessage::ToggleMute,
+            Action::SpeedUp => PlayerMessage::IncrSpeed,
+            Action::SlowDown => PlayerMessage::DecrSpeed,
+            Action::ResetSpeed => PlayerMessage::ResetSpeed,
+            Action::StepForward => PlayerMessage::StepFrame(true),
+            Action::StepBackward => PlayerMessage::StepFrame(false),
+            Action::PreviousChapter => PlayerMessage::PreviousChapter,
+            Action::NextChapter => PlayerMessage::NextChapter,
+            Action::PlayPrevious => PlayerMessage::PlayPrevious,
+            Action::PlayNext => PlayerMessage::PlayNext,
+            Action::ToggleQueue => PlayerMessage::Queue(QueueMessage::Toggle),
+            Action::ToggleSubtitles => PlayerMessage::ToggleSubtitles,
+            Action::SubtitlesEarlier => PlayerMessage::SubtitleDelay(-Self::DELAY_STEP),
+            Action::SubtitlesLater => PlayerMessage::SubtitleDelay(Self::DELAY_STEP),
+            Action::AudioEarlier => PlayerMessage::AudioDelay(-Self::AUDIO_DELAY_STEP),
+            Action::AudioLater => PlayerMessage::AudioDelay(Self::AUDIO_DELAY_STEP),
+            Action::CycleLoop => PlayerMessage::Loop(LoopMessage::Cycle),
+            Action::LoopStartEarlier => PlayerMessage::Loop(LoopMessage::NudgeA(-Self::LOOP_NUDGE)),
+            Action::LoopStartLater => PlayerMessage::Loop(LoopMessage::NudgeA(Self::LOOP_NUDGE)),
+            Action::LoopEndEarlier => PlayerMessage::Loop(LoopMessage::NudgeB(-Self::LOOP_NUDGE)),
+            Action::LoopEndLater => PlayerMessage::Loop(LoopMessage::NudgeB(Self::LOOP_NUDGE)),
+            Action::Screenshot => PlayerMessage::Screenshot,
+            Action::AddBookmark => PlayerMessage::Bookmark(BookmarkMessage::Add),
+            Action::SetClipIn => PlayerMessage::Export(ExportMessage::SetIn),
+            Action::SetClipOut => PlayerMessage::Export(ExportMessage::SetOut),
+            Action::MarkCredits => PlayerMessage::MarkCredits,
+            Action::ShowConfig => PlayerMessage::Config,
+            Action::LeavePlayer => PlayerMessage::PreviousScreen,
+            Action::Back
+            | Action::Forward
+            | Action::PlayQueue
+            | Action::ToggleView
+            | Action::Randomize
+            | Action::Home
+            | Action::Settings => return None,
+        };
+
+        Some(message)
     }
 
-    fn seek_amount(&self) -> f64 {
-        10.0
-    }
+    pub fn update(&mut self, message: PlayerMessage) -> Task<PlayerMessage> {
+        match message {
+            PlayerMessage::WindowId(id) => {
+                self.window_id = id;
+
+                match id {
+                    Some(id) => {
+                        window::get_size(id).map(move |size| PlayerMessage::Resize((id, size)))
+                    }
+                    None => Task::none(),
+                }
+            }
+            PlayerMessage::Resize((id, size)) => {
+                if Some(id) == self.window_id {
+                    self.window_size = size;
+                    let maximised = Size::new(1000., 1000.);
+                    self.maximised =
+                        size.width >= maximised.width && size.height >= maximised.height;
+                    self.show_controls = !self.maximised;
+                }
+                Task::none()
+            }
+            PlayerMessage::None => Task::none(),
+            PlayerMessage::Chord(chord) => match self.resolve(&chord) {
+                Some(message) => self.update(message),
+                None => Task::none(),
+            },
+            PlayerMessage::FontLoad(Err(error)) => {
+                eprintln!("{error:?}");
+                Task::none()
+            }
+            PlayerMessage::FontLoad(_) => Task::none(),
+            PlayerMessage::EndOfStream => {
+                if let (Some(a), Some(_)) = (self.loop_a, self.loop_b) {
+                    self.seek_to(Duration::from_secs_f64(a));
+                    self.video.set_paused(false);
+                    return Task::none();
+                }
+
+                // todo: Send message to main with some video stats
+                if self.repeats_current() {
+                    // Replays straight away rather than counting down.
+                    self.replay();
+                    return Task::none();
+                }
+
+                self.show_up_next();
+                Task::none()
+            }
+            PlayerMessage::NewFrame => {
+                if !self.is_dragging {
+                    self.position = self.video.position().as_secs_f64();
+                }
+                self.update_cue();
+
+                if let (Some(a), Some(b)) = (self.loop_a, self.loop_b) {
+                    if !self.is_dragging && self.position >= b {
+                        self.seek_to(Duration::from_secs_f64(a));
+                        return Task::none();
+                    }
+                }
+
+                if self
+                    .credits()
+                    .is_some_and(|credits| self.position >= credits.as_secs_f64())
+                {
+                    self.show_up_next();
+                }
+
+                Task::none()
+            }
+            PlayerMessage::Countdown => {
+                let Some(up_next) = self.up_next.as_mut() else {
+                    return Task::none();
+                };
+
+                up_next.remaining = up_next.remaining.saturating_sub(1);
+
+                if up_next.remaining == 0 {
+                    self.update(PlayerMessage::PlayNow)
+                } else {
+                    Task::none()
+                }
+            }
+            PlayerMessage::PlayNow => {
+                if self.repeats_current() {
+                    self.replay();
+                    return Task::none();
+                }
+
+                match self.queue.next() {
+                    Some(_) => self.play_current(),
+                    None => {
+                        self.up_next = None;
+                        Task::none()
+                    }
+                }
+            }
+            PlayerMessage::CancelNext => {
+                self.up_next = None;
+                self.up_next_cancelled = true;
+                Task::none()
+            }
+            PlayerMessage::MarkCredits => {
+                let position = (self.position.max(0.0) * 1000.0) as u64;
+
+                if let Some(video) = self.queue.current_mut() {
+                    video.credits = Some(position);
+                }
+                self.config.credits = Some(position);
+                self.save_config();
+
+                self.up_next_cancelled = false;
+                self.show_up_next();
+                Task::none()
+            }
+            PlayerMessage::SeekRelease => {
+                self.is_dragging = false;
+                self.video
+                    .seek(Duration::from_secs_f64(self.position.max(0.0)), false)
+                    .unwrap();
+
+                self.video.set_paused(false);
+                Task::none()
+            }
+            PlayerMessage::Seek(pos) => {
+                self.position = pos.max(0.0);
+                self.update_cue();
+                self.is_dragging = true;
+                self.video.set_paused(true);
+                Task::none()
+            }
+            PlayerMessage::TogglePlay => {
+                if self.video.eos() {
+                    self.position = 0.0;
+                    self.up_next = None;
+                    self.video.seek(Duration::ZERO, false).unwrap();
+                    self.video.set_paused(false);
+                } else {
+                    self.video.set_paused(!self.video.paused());
+                }
+                Task::none()
+            }
+            PlayerMessage::ThumbnailsReady(Err(_err)) => {
+                todo!("Set up error toasts here");
+            }
+            PlayerMessage::ThumbnailsReady(Ok(thumbnails)) => {
+                self.thumbnails = thumbnails;
+                Task::none()
+            }
+            PlayerMessage::ChangeVolume(volume) => {
+                self.set_volume(volume);
+                Task::none()
+            }
+            PlayerMessage::ToggleMute => {
+                let mute = !self.video.muted();
+                self.video.set_muted(mute);
+
+                if mute {
+                    self.volume = 0.0
+                } else {
+                    self.volume = self.video.volume() * self.boost;
+                }
+                Task::none()
+            }
+            PlayerMessage::SeekBack(shift) => {
+                self.is_dragging = false;
+                self.position = (self.position - self.seek_amount(shift)).max(0.0);
+                self.video
+                    .seek(Duration::from_secs_f64(self.position), false)
+                    .unwrap();
+                self.update_cue();
+                Task::none()
+            }
+            PlayerMessage::SeekFront(shift) => {
+                self.is_dragging = false;
+                let duration = self.video.duration().as_secs_f64();
+                self.position = (self.position + self.seek_amount(shift)).min(duration);
+                self.video
+                    .seek(Duration::from_secs_f64(self.position), false)
+                    .unwrap();
+                self.update_cue();
+                Task::none()
+            }
+            PlayerMessage::IncrVolume => {
+                self.set_volume((self.volume + self.volume_amount()).min(Self::MAX_VOLUME));
+                if self.volume > 1.0 {
+                    let volume = format!("{:.0}", self.volume * 100.0);
+                    self.show_osd(t!("player-volume", volume = volume));
+                }
+                Task::none()
+            }
+            PlayerMessage::DecrVolume => {
+                self.set_volume((self.volume - self.volume_amount()).max(0.0));
+                if self.volume > 1.0 {
+                    let volume = format!("{:.0}", self.volume * 100.0);
+                    self.show_osd(t!("player-volume", volume = volume));
+                }
+                Task::none()
+            }
+            PlayerMessage::IncrSpeed => {
+                self.set_speed(self.speed + self.speed_amount());
+                Task::none()
+            }
+            PlayerMessage::DecrSpeed => {
+                self.set_speed(self.speed - self.speed_amount());
+                Task::none()
+            }
+            PlayerMessage::ResetSpeed => {
+                self.set_speed(1.0);
+                Task::none()
+            }
+            PlayerMessage::CursorExit => {
+                if self.is_fullscreen || self.maximised {
+                    self.show_controls = false;
+                }
+                Task::none()
+            }
+            PlayerMessage::CursorEnter => {
+                self.show_controls = true;
+                Task::none()
+            }
+            PlayerMessage::ToggleFullscreen => {
+                self.show_controls = self.is_fullscreen;
+                self.is_fullscreen = !self.is_fullscreen;
+                let fullscreen = self.is_fullscreen;
+                iced::window::get_latest()
+                    .and_then(move |id| {
+                        iced::window::set_mode::<()>(
+                            id,
+                            if fullscreen {
+                                iced::window::Mode::Fullscreen
+                            } else {
+                                iced::window::Mode::Windowed
+                            },
+                        )
+                    })
+                    .discard()
+            }
+            PlayerMessage::ExitFullscreen => {
+                self.show_controls = true;
+                self.is_fullscreen = false;
+                let fullscreen = self.is_fullscreen;
+                iced::window::get_latest()
+                    .and_then(move |id| {
+                        iced::window::set_mode::<()>(
+                            id,
+                            if fullscreen {
+                                iced::window::Mode::Fullscreen
+                            } else {
+                                iced::window::Mode::Windowed
+                            },
+                        )
+                    })
+                    .discard()
+            }
+            // Leaving the player is handled by the screen hosting it.
+            PlayerMessage::PreviousScreen => Task::none(),
+            PlayerMessage::AddCollection => Task::none(),
+            PlayerMessage::Config => {
+                self.show_adjustments = !self.show_adjustments;
+                Task::none()
+            }
+            PlayerMessage::ToggleSubtitles => {
+                self.show_subtitles = !self.show_subtitles;
+                Task::none()
+            }
+            PlayerMessage::SubtitlesLoaded(subtitles, select) => {
+                let first = self.subtitles.len();
+                self.subtitles.extend(subtitles);
+
+                if first == self.subtitles.len() {
+                    return Task::none();
+                }
+
+                if select {
+                    self.select_subtitles(Some(SubtitleSource::External(first)));
+                    self.remember_tracks();
+                    return Task::none();
+                }
+
+                match self.config.subtitles.as_ref() {
+                    Some(choice @ SubtitleChoice::External(_)) => {
+                        if let Some(source) = self.source(choice) {
+                            self.select_subtitles(Some(source));
+                        }
+                    }
+                    Some(_) => {}
+                    None => self.select_subtitles(self.auto_subtitles()),
+                }
+
+                if let Some(choice @ SubtitleChoice::External(_)) =
+                    self.config.secondary_subtitles.as_ref()
+                {
+                    if let Some(source) = self.source(choice) {
+                        self.select_secondary(Some(source));
+                    }
+                }
+                Task::none()
+            }
+            PlayerMessage::SelectSubtitles(source) => {
+                self.select_subtitles(source);
+                self.remember_tracks();
+                Task::none()
+            }
+            PlayerMessage::SelectSecondarySubtitles(source) => {
+                self.select_secondary(source);
+                self.remember_tracks();
+                Task::none()
+            }
+            PlayerMessage::SubtitleDelay(change) => {
+                self.config.subtitle_delay += change;
+                tracks::set_text_offset(&self.video.pipeline(), self.config.subtitle_delay);
+                self.update_cue();
+                self.save_config();
+
+                let delay = format!("{:+}", self.config.subtitle_delay);
+                self.show_osd(t!("player-subtitle-delay", delay = delay));
+                Task::none()
+            }
+            PlayerMessage::AudioDelay(change) => {
+                self.config.audio_delay += change;
+                audio::set_delay(&self.video.pipeline(), self.config.audio_delay);
+                self.save_config();
+
+                let delay = format!("{:+}", self.config.audio_delay);
+                self.show_osd(t!("player-audio-delay", delay = delay));
+                Task::none()
+            }
+            PlayerMessage::Tick => {
+                if self
+                    .osd
+                    .as_ref()
+                    .is_some_and(|(_, shown)| shown.elapsed() >= Self::OSD_DURATION)
+                {
+                    self.osd = None;
+                }
+
+                if self
+                    .toast
+                    .as_ref()
+                    .is_some_and(|toast| toast.shown.elapsed() >= Self::TOAST_DURATION)
+                {
+                    self.toast = None;
+                }
+                Task::none()
+            }
+            PlayerMessage::Screenshot => self.screenshot(),
+            PlayerMessage::ScreenshotSaved(Ok(path)) => {
+                let shown = path.display().to_string();
+                self.show_toast(t!("player-screenshot-saved", path = shown), Some(path));
+                Task::none()
+            }
+            PlayerMessage::ScreenshotSaved(Err(err)) => {
+                self.show_toast(
+                    t!("player-screenshot-failed", error = err.to_string()),
+                    None,
+                );
+                Task::none()
+            }
+            PlayerMessage::AudioFiltersFailed(err) => {
+                eprintln!("Error adding audio filters: \n{err}");
+                self.show_toast(t!("player-audio-filters-failed", error = err), None);
+                Task::none()
+            }
+            PlayerMessage::TracksChanged => {
+                self.restore_tracks();
+                self.apply_audio();
+                Task::none()
+            }
+            // The library copy of the video is updated by the screen hosting the player.
+            PlayerMessage::UseAsPoster(path) => {
+                if let Some(video) = self.queue.current_mut() {
+                    video.poster = Some(path.to_string_lossy().into_owned());
+                }
+
+                self.show_toast(t!("player-poster-updated"), None);
+                Task::none()
+            }
+            PlayerMessage::DismissToast => {
+                self.toast = None;
+                Task::none()
+            }
+            PlayerMessage::Export(message) => self.update_export(message),
+            PlayerMessage::Adjust(message) => {
+                self.update_adjustments(message);
+                Task::none()
+            }
+            PlayerMessage::Audio(message) => {
+                self.update_audio(message);
+                Task::none()
+            }
+            PlayerMessage::Frame(message) => {
+                let auto_crop = matches!(message, FrameMessage::AutoCrop(true));
+                self.update_framing(message);
+
+                if auto_crop {
+                    self.detect_crop()
+                } else {
+                    Task::none()
+                }
+            }
+            PlayerMessage::CropDetected(path, Some(crop)) => {
+                self.set_detected_crop(path, crop);
+                Task::none()
+            }
+            PlayerMessage::CropDetected(_, None) => Task::none(),
+            PlayerMessage::Bookmark(message) => {
+                self.update_bookmarks(message);
+                Task::none()
+            }
+            PlayerMessage::SelectAudio(index) => {
+                tracks::select(&self.video.pipeline(), TrackKind::Audio, index);
+                self.audio = index;
+                self.remember_tracks();
+                // Another stream may carry its own ReplayGain.
+                self.apply_audio();
+                Task::none()
+            }
+            PlayerMessage::SubtitleText(text) => {
+                if self.subtitle.is_some_and(|source| source.is_embedded()) {
+                    self.cue = text;
+                } else if self.secondary.is_some_and(|source| source.is_embedded()) {
+                    self.secondary_cue = text;
+                }
+                Task::none()
+            }
+            PlayerMessage::PickSubtitles => Task::perform(
+                rfd::AsyncFileDialog::new()
+                    .add_filter(t!("player-subtitle-files"), &subtitles::Format::EXTENSIONS)
+                    .pick_file(),
+                |file| PlayerMessage::SubtitlesPicked(file.map(|file| file.path().to_owned())),
+            ),
+            PlayerMessage::SubtitlesPicked(Some(path)) => Self::load_subtitles(vec![path], true),
+            PlayerMessage::SubtitlesPicked(None) => Task::none(),
+            PlayerMessage::ChaptersLoaded(chapters) => {
+                self.chapters = chapters;
+                Task::none()
+            }
+            PlayerMessage::PreviousChapter => {
+                let position = Duration::from_secs_f64(self.position.max(0.0));
+
+                if let Some(current) = chapters::at(&self.chapters, position) {
+                    // Restart the current chapter unless it has only just begun.
+                    let elapsed = position.saturating_sub(self.chapters[current].start);
+                    let index = if elapsed > Duration::from_secs(3) {
+                        current
+                    } else {
+                        current.saturating_sub(1)
+                    };
+
+                    self.seek_chapter(index);
+                }
+                Task::none()
+            }
+            PlayerMessage::NextChapter => {
+                let position = Duration::from_secs_f64(self.position.max(0.0));
+                let next = chapters::at(&self.chapters, position).map_or(0, |index| index + 1);
+
+                self.seek_chapter(next);
+                Task::none()
+            }
+            PlayerMessage::SeekChapter(index) => {
+                self.seek_chapter(index);
+                Task::none()
+            }
+            PlayerMessage::Loop(message) => {
+                self.update_loop(message);
+                Task::none()
+            }
+            PlayerMessage::StepFrame(forward) => {
+                self.step_frame(forward);
+                Task::none()
+            }
+            PlayerMessage::PlayNext => match self.queue.next() {
+                Some(_) => self.play_current(),
+                None => Task::none(),
+            },
+            PlayerMessage::PlayPrevious => match self.queue.previous() {
+                Some(_) => self.play_current(),
+                None => Task::none(),
+            },
+            PlayerMessage::Queue(message) => match message {
+                QueueMessage::Toggle => {
+                    self.show_queue = !self.show_queue;
+                    Task::none()
+                }
+                QueueMessage::Shuffle => {
+                    self.queue.toggle_shuffle();
+                    Task::none()
+                }
+                QueueMessage::Repeat => {
+                    self.queue.repeat.cycle();
+                    if self.repeats_current() {
+                        self.up_next = None;
+                    }
+                    Task::none()
+                }
+                QueueMessage::Jump(position) => {
+                    if position == self.queue.position() {
+                        return Task::none();
+                    }
+
+                    match self.queue.jump(position) {
+                        Some(_) => self.play_current(),
+                        None => Task::none(),
+                    }
+                }
+                QueueMessage::MoveUp(position) => {
+                    self.queue.move_up(position);
+                    Task::none()
+                }
+                QueueMessage::MoveDown(position) => {
+                    self.queue.move_down(position);
+                    Task::none()
+                }
+                QueueMessage::Remove(position) => {
+                    self.queue.remove(position);
+                    Task::none()
+                }
+            },
+            PlayerMessage::Favorite => Task::none(),
+            PlayerMessage::Comment => Task::none(),
+        }
+    }
+
+    pub fn subscriptions(&self) -> Subscription<PlayerMessage> {
+        let resize = window::resize_events().map(PlayerMessage::Resize);
+
+        let countdown = if self.up_next.is_some() {
+            iced::time::every(Duration::from_secs(1)).map(|_| PlayerMessage::Countdown)
+        } else {
+            Subscription::none()
+        };
+
+        let osd = if self.osd.is_some() || self.toast.is_some() {
+            iced::time::every(Duration::from_millis(250)).map(|_| PlayerMessage::Tick)
+        } else {
+            Subscription::none()
+        };
+
+        let framing = event::listen_with(|event, _, _| match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                Some(PlayerMessage::Frame(FrameMessage::Modifiers(modifiers)))
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 50.0,
+                };
+                Some(PlayerMessage::Frame(FrameMessage::Wheel(lines)))
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                Some(PlayerMessage::Frame(FrameMessage::PanStart))
+            }
+            _ => None,
+        });
+
+        let panning = if self.panning.is_some() {
+            event::listen_with(|event, _, _| match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    Some(PlayerMessage::Frame(FrameMessage::Pan(position)))
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)) => {
+                    Some(PlayerMessage::Frame(FrameMessage::PanEnd))
+                }
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+
+        Subscription::batch([resize, countdown, osd, framing, panning])
+    }
+
+    fn name(&self) -> &str {
+        self.queue
+            .current()
+            .map(|video| video.name.as_str())
+            .unwrap_or_default()
+    }
+
+    fn volume_amount(&self) -> f64 {
+        self.settings.playback.volume_step
+    }
+
+    /// The seconds skipped by a seek, further while shift is held.
+    fn seek_amount(&self, shift: bool) -> f64 {
+        if shift {
+            self.settings.playback.long_seek
+        } else {
+            self.settings.playback.seek
+        }
+    }
+
+    fn speed_amount(&self) -> f64 {
+        self.settings.playback.speed_step
+    }
+
+    fn play_btn(&self) -> (char, PlayerMessage) {
+        if self.video.eos() {
+            (icons::REPLAY, PlayerMessage::TogglePlay)
+        } else if self.video.paused() {
+            (icons::PLAY, PlayerMessage::TogglePlay)
+        } else {
+            (icons::PAUSE, PlayerMessage::TogglePlay)
+        }
+    }
+
+    fn top(&self) -> Element<'_, PlayerMessage> {
+        let title = text(self.name());
+        let options = column!(
+            row!(
+                text_button(icons::LIST).on_press(PlayerMessage::Queue(QueueMessage::Toggle)),
+                text_button(icons::ADD_COLLECTION).on_press(PlayerMessage::AddCollection),
+                text_button(icons::VIDEO_CONFIG).on_press(PlayerMessage::Config),
+                self.more_menu()
+            )
+            .spacing(6.0)
+            .align_y(Vertical::Center)
+        )
+        .align_x(Horizontal::Right)
+        .width(Self::WIDTH);
+        let back = container(text_button(icons::BACK).on_press(PlayerMessage::PreviousScreen))
+            .align_x(Horizontal::Left)
+            .align_y(Vertical::Center)
+            .width(Self::WIDTH);
+
+        let content = row!(back, horizontal_space(), title, horizontal_space(), options)
+            .width(Length::Fill)
+            .align_y(Vertical::Center);
+
+        let content: Element<'_, PlayerMessage> = if self.show_controls {
+            content.into()
+        } else {
+            horizontal_space().height(35).into()
+        };
+
+        let content = mouse_area(content)
+            .on_exit(PlayerMessage::CursorExit)
+            .on_enter(PlayerMessage::CursorEnter);
+
+        content.into()
+    }
+
+    fn media_controls(&self) -> Element<'_, PlayerMessage> {
+        let left = {
+            let volume = slider(
+                0.0..=Self::MAX_VOLUME,
+                self.volume,
+                PlayerMessage::ChangeVolume,
+            )
+            .step(0.05)
+            .shift_step(0.1)
+            .width(125.0);
+            row!(
+                self.tracks_menu(),
+                text_button(if self.video.muted() {
+                    icons::MUTE
+                } else {
+                    icons::VOLUME
+                })
+                .on_press(PlayerMessage::ToggleMute),
+                volume
+            )
+            .spacing(2.0)
+            .align_y(Vertical::Center)
+        }
+        .width(Self::WIDTH);
+
+        let middle = {
+            let (play, message) = self.play_btn();
+
+            row!(
+                text_button(icons::PREVIOUS_VIDEO).on_press_maybe(
+                    self.queue
+                        .has_previous()
+                        .then_some(PlayerMessage::PlayPrevious)
+                ),
+                self.loop_menu(),
+                text_button(icons::SEEK_BACK).on_press(PlayerMessage::SeekBack(false)),
+                text_button(play).on_press(message),
+                text_button(icons::SEEK_FRONT).on_press(PlayerMessage::SeekFront(false)),
+                text_button(icons::NEXT_VIDEO)
+                    .on_press_maybe(self.queue.has_next().then_some(PlayerMessage::PlayNext))
+            )
+            .spacing(2.0)
+            .align_y(Vertical::Center)
+        };
+
+        let right = column!(
+            row!(
+                self.bookmarks_menu(),
+                self.chapters_menu(),
+                text_button(icons::FAVORITE).on_press(PlayerMessage::Favorite),
+                text_button(icons::COMMENT).on_press(PlayerMessage::Comment),
+                text_button(if self.is_fullscreen {
+                    icons::MINIMIZE
+                } else {
+                    icons::MAXIMIZE
+                })
+                .on_press(PlayerMessage::ToggleFullscreen)
+            )
+            .spacing(2.0)
+            .align_y(Vertical::Center)
+        )
+        .align_x(Horizontal::Right)
+        .width(Self::WIDTH);
+
+        let content = row!(left, horizontal_space(), middle, horizontal_space(), right)
+            .width(Length::Fill)
+            .align_y(Vertical::Center);
+
+        let timeline = {
+            let duration = self.video.duration();
+            let spent = format!(
+                "{:02}:{:02}:{:02}",
+                self.position as u64 / 3600,
+                self.position as u64 / 60,
+                self.position as u64 % 60,
+            );
+            let total = format!(
+                "{:02}:{:02}:{:02}",
+                duration.as_secs() / 3600,
+                duration.as_secs() / 60,
+                duration.as_secs() % 60,
+            );
+
+            let slider = widgets::slider::VideoSlider::new(
+                0.0..=duration.as_secs_f64(),
+                self.position,
+                PlayerMessage::Seek,
+                self.thumbnails.clone(),
+                Font::default(),
+                duration,
+            )
+            .step(0.1)
+            .style(theme::seek_bar)
+            .chapters(
+                self.chapters
+                    .iter()
+                    .enumerate()
+                    .map(|(i, chapter)| (chapter.start.as_secs_f64(), chapter.label(i))),
+            )
+            .markers(
+                [
+                    self.loop_a.map(|value| Marker {
+                        value,
+                        color: LOOP_A_COLOR,
+                    }),
+                    self.loop_b.map(|value| Marker {
+                        value,
+                        color: LOOP_B_COLOR,
+                    }),
+                    self.clip_in.map(|value| Marker {
+                        value,
+                        color: CLIP_COLOR,
+                    }),
+                    self.clip_out.map(|value| Marker {
+                        value,
+                        color: CLIP_COLOR,
+                    }),
+                ]
+                .into_iter()
+                .flatten()
+                .chain(self.config.bookmarks.iter().map(|bookmark| Marker {
+                    value: bookmark.position,
+                    color: BOOKMARK_COLOR,
+                })),
+            )
+            .highlight(
+                (self.clip_in.is_some() || self.clip_out.is_some()).then(|| {
+                    let (start, end) = self.clip();
+                    start..=end
+                }),
+                CLIP_COLOR.scale_alpha(0.5),
+            )
+            .highlight(
+                self.loop_a.zip(self.loop_b).map(|(a, b)| a..=b),
+                LOOP_A_COLOR.scale_alpha(0.5),
+            )
+            .on_release(PlayerMessage::SeekRelease);
+
+            row!(text(spent), slider, text(total))
+                .spacing(20.0)
+                .align_y(Vertical::Center)
+                .width(Length::Fill)
+        };
+
+        let content = column!(timeline, content, vertical_space().height(8.0))
+            .spacing(8)
+            .width(Length::Fill);
+
+        let content: Element<'_, PlayerMessage> = if self.show_controls {
+            content.into()
+        } else {
+            horizontal_space().height(75).into()
+        };
+
+        let content = mouse_area(content)
+            .on_exit(PlayerMessage::CursorExit)
+            .on_enter(PlayerMessage::CursorEnter);
+
+        content.into()
+    }
+
+    fn tracks_menu(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let showing = self.show_subtitles && (self.subtitle.is_some() || self.secondary.is_some());
+
+        let header = |label: &str| Element::from(text(label.to_owned()).size(size));
+        let item = |label: String, selected: bool, message: PlayerMessage| {
+            Element::from(
+                button(text(label).size(size))
+                    .width(Length::Fill)
+                    .padding([3, 6])
+                    .style(if selected {
+                        button::secondary
+                    } else {
+                        button::text
+                    })
+                    .on_press(message),
+            )
+        };
+
+        let mut items = vec![header(&t!("menu-audio"))];
+
+        items.extend(self.audio_tracks.iter().map(|track| {
+            item(
+                track.label(),
+                track.index == self.audio,
+                PlayerMessage::SelectAudio(track.index),
+            )
+        }));
+
+        let sources =
+            |current: Option<SubtitleSource>,
+             on_select: fn(Option<SubtitleSource>) -> PlayerMessage| {
+                let current = current.filter(|_| self.show_subtitles);
+
+                let embedded = self
+                    .text_tracks
+                    .iter()
+                    .map(|track| (track.label(), SubtitleSource::Embedded(track.index)));
+                let external = self.subtitles.iter().enumerate().map(|(i, subtitles)| {
+                    (subtitles.name().to_owned(), SubtitleSource::External(i))
+                });
+
+                let sources = embedded.chain(external).map(|(label, source)| {
+                    item(label, current == Some(source), on_select(Some(source)))
+                });
+
+                std::iter::once(item(t!("common-off"), current.is_none(), on_select(None)))
+                    .chain(sources)
+                    .collect::<Vec<_>>()
+            };
+
+        items.push(header(&t!("menu-subtitles")));
+        items.extend(sources(self.subtitle, PlayerMessage::SelectSubtitles));
+
+        items.push(header(&t!("menu-secondary-subtitles")));
+        items.extend(sources(
+            self.secondary,
+            PlayerMessage::SelectSecondarySubtitles,
+        ));
+
+        items.push(item(
+            t!("menu-load-file"),
+            false,
+            PlayerMessage::PickSubtitles,
+        ));
+
+        let overlay = container(scrollable(column(items).spacing(2.0).width(260.0)))
+            .padding(6)
+            .max_height(400.0)
+            .style(theme::panel);
+
+        let base = container(icons::icon(if showing {
+            icons::SUBTITLES_ON
+        } else {
+            icons::SUBTITLES_OFF
+        }))
+        .padding([5, 10]);
+
+        widgets::menu::menu(base, overlay)
+            .position(widgets::menu::Position::Top)
+            .into()
+    }
+
+    fn more_menu(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+
+        let item = |label: String, message: PlayerMessage| {
+            Element::from(
+                button(text(label).size(size))
+                    .width(Length::Fill)
+                    .padding([3, 6])
+                    .style(button::text)
+                    .on_press(message),
+            )
+        };
+
+        let items = vec![
+            item(
+                t!("menu-add-bookmark"),
+                PlayerMessage::Bookmark(BookmarkMessage::Add),
+            ),
+            item(t!("menu-screenshot"), PlayerMessage::Screenshot),
+            item(
+                t!("menu-export"),
+                PlayerMessage::Export(ExportMessage::Toggle),
+            ),
+        ];
+
+        let overlay = container(column(items).spacing(2.0).width(200.0))
+            .padding(6)
+            .style(theme::panel);
+
+        let base = container(icons::icon(icons::EQUALS)).padding([5, 10]);
+
+        widgets::menu::menu(base, overlay)
+            .position(widgets::menu::Position::Bottom)
+            .into()
+    }
+
+    fn loop_menu(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+
+        let marker = |label: &'static str,
+                      value: Option<f64>,
+                      set: LoopMessage,
+                      nudge: fn(f64) -> LoopMessage| {
+            row!(
+                text(label).size(size).width(20.0),
+                text(value.map(timestamp).unwrap_or_else(|| "--:--:--.-".into()))
+                    .size(size)
+                    .width(Length::Fill),
+                sized_button(icons::CHEV_LEFT, size)
+                    .on_press_maybe(value.map(|_| PlayerMessage::Loop(nudge(-Self::LOOP_NUDGE)))),
+                sized_button(icons::CHEV_RIGHT, size)
+                    .on_press_maybe(value.map(|_| PlayerMessage::Loop(nudge(Self::LOOP_NUDGE)))),
+                button(text(t!("common-set")).size(size))
+                    .padding([2, 8])
+                    .style(button::secondary)
+                    .on_press(PlayerMessage::Loop(set)),
+            )
+            .spacing(4.0)
+            .align_y(Vertical::Center)
+        };
+
+        let clear = button(text(t!("common-clear")).size(size))
+            .padding([2, 8])
+            .style(button::text)
+            .on_press_maybe(
+                (self.loop_a.is_some() || self.loop_b.is_some())
+                    .then_some(PlayerMessage::Loop(LoopMessage::Clear)),
+            );
+
+        let overlay = container(
+            column!(
+                text(t!("loop-title")).size(size),
+                marker("A", self.loop_a, LoopMessage::SetA, LoopMessage::NudgeA),
+                marker("B", self.loop_b, LoopMessage::SetB, LoopMessage::NudgeB),
+                row!(horizontal_space(), clear)
+            )
+            .spacing(6.0)
+            .width(240.0),
+        )
+        .padding(8)
+        .style(theme::panel);
+
+        let looping = self.loop_a.is_some() && self.loop_b.is_some();
+        let base = container(icons::icon(if looping {
+            icons::LOOP
+        } else {
+            icons::UNLOOP
+        }))
+        .padding([5, 10]);
+
+        widgets::menu::menu(base, overlay)
+            .position(widgets::menu::Position::Top)
+            .into()
+    }
+
+    fn bookmarks_menu(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let message = PlayerMessage::Bookmark;
+
+        let header = row!(
+            text(t!("bookmarks-title")).size(size).width(Length::Fill),
+            button(text(t!("common-add")).size(size))
+                .padding([2, 8])
+                .style(button::secondary)
+                .on_press(message(BookmarkMessage::Add))
+        )
+        .align_y(Vertical::Center);
+
+        let bookmarks = self
+            .config
+            .bookmarks
+            .iter()
+            .enumerate()
+            .map(|(i, bookmark)| {
+                let name: Element<'_, PlayerMessage> = match self.renaming.as_ref() {
+                    Some((index, name)) if *index == i => text_input(&t!("bookmarks-name"), name)
+                        .size(size)
+                        .padding([2, 6])
+                        .on_input(|name| message(BookmarkMessage::Rename(name)))
+                        .on_submit(message(BookmarkMessage::FinishRename))
+                        .into(),
+                    _ => button(
+                        row!(
+                            text(bookmark.name.as_str()).size(size).width(Length::Fill),
+                            text(timestamp(bookmark.position)).size(size)
+                        )
+                        .spacing(12.0),
+                    )
+                    .width(Length::Fill)
+                    .padding([3, 6])
+                    .style(button::text)
+                    .on_press(message(BookmarkMessage::Jump(i)))
+                    .into(),
+                };
+
+                let rename = match self.renaming.as_ref() {
+                    Some((index, _)) if *index == i => button(text(t!("common-save")).size(size))
+                        .on_press(message(BookmarkMessage::FinishRename)),
+                    _ => button(text(t!("bookmarks-rename")).size(size))
+                        .on_press(message(BookmarkMessage::StartRename(i))),
+                }
+                .padding([2, 6])
+                .style(button::text);
+
+                row!(
+                    name,
+                    rename,
+                    sized_button(icons::CANCEL, size).on_press(message(BookmarkMessage::Delete(i)))
+                )
+                .spacing(4.0)
+                .align_y(Vertical::Center)
+                .into()
+            });
+
+        let overlay = container(
+            column!(header, scrollable(column(bookmarks).spacing(2.0)))
+                .spacing(6.0)
+                .width(320.0),
+        )
+        .padding(6)
+        .max_height(400.0)
+        .style(theme::panel);
+
+        let base = container(icons::icon(icons::BOOKMARK)).padding([5, 10]);
+
+        widgets::menu::menu(base, overlay)
+            .position(widgets::menu::Position::Top)
+            .into()
+    }
+
+    fn chapters_menu(&self) -> Element<'_, PlayerMessage> {
+        if self.chapters.is_empty() {
+            return horizontal_space().width(0).into();
+        }
+
+        let size = h7();
+        let position = Duration::from_secs_f64(self.position.max(0.0));
+        let current = chapters::at(&self.chapters, position);
+
+        let items = self.chapters.iter().enumerate().map(|(i, chapter)| {
+            let start = chapter.start.as_secs();
+            let start = format!(
+                "{:02}:{:02}:{:02}",
+                start / 3600,
+                start % 3600 / 60,
+                start % 60
+            );
+
+            button(
+                row!(
+                    text(chapter.label(i)).size(size).width(Length::Fill),
+                    text(start).size(size)
+                )
+                .spacing(12.0),
+            )
+            .width(Length::Fill)
+            .padding([3, 6])
+            .style(if current == Some(i) {
+                button::secondary
+            } else {
+                button::text
+            })
+            .on_press(PlayerMessage::SeekChapter(i))
+            .into()
+        });
+
+        let overlay = container(scrollable(column(items).spacing(2.0).width(280.0)))
+            .padding(6)
+            .max_height(400.0)
+            .style(theme::panel);
+
+        let base = container(icons::icon(icons::NUMBER)).padding([5, 10]);
+
+        widgets::menu::menu(base, overlay)
+            .position(widgets::menu::Position::Top)
+            .into()
+    }
+
+    /// Lays out the primary cue at the bottom of the video and the secondary
+    /// one at the top, sized relative to the video within `size`.
+    fn subtitles_layer(&self, size: Size) -> Element<'_, PlayerMessage> {
+        let style = &self.settings.subtitles;
+
+        let (width, height) = self.video.size();
+        let video_height = if width > 0 && height > 0 {
+            size.height.min(size.width * height as f32 / width as f32)
+        } else {
+            size.height
+        };
+
+        let bars = (size.height - video_height) / 2.0;
+        let offset = bars + video_height * style.position;
+        let text_size = (video_height * style.size).max(10.0);
+
+        let (top, bottom) = if self.show_controls {
+            (offset.max(60.0), offset.max(130.0))
+        } else {
+            (offset, offset)
+        };
+
+        let secondary = match self.secondary_cue.as_deref() {
+            Some(cue) => center_x(self.styled_cue(cue, text_size)).into(),
+            None => Element::from(horizontal_space()),
+        };
+        let primary = match self.cue.as_deref() {
+            Some(cue) => center_x(self.styled_cue(cue, text_size)).into(),
+            None => Element::from(horizontal_space()),
+        };
+
+        column!(secondary, vertical_space(), primary)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(Padding::ZERO.top(top).bottom(bottom))
+            .into()
+    }
+
+    fn styled_cue<'a>(&'a self, cue: &'a str, size: f32) -> Element<'a, PlayerMessage> {
+        let style = &self.settings.subtitles;
+        let outline = style.outline;
+        let background = style.background;
+
+        // iced has no text outlines, so offset copies are drawn underneath.
+        let line = |color: Color, x: f32, y: f32| {
+            Element::from(
+                container(
+                    text(cue)
+                        .size(size)
+                        .font(self.subtitle_font)
+                        .color(color)
+                        .align_x(Horizontal::Center),
+                )
+                .padding(Padding {
+                    top: outline + y,
+                    right: outline - x,
+                    bottom: outline - y,
+                    left: outline + x,
+                }),
+            )
+        };
+
+        let mut layers = vec![];
 
-    fn speed_amount(&self) -> f64 {
-        0.1
-    }
+        if outline > 0.0 {
+            let color = style.outline_color();
 
-    fn play_btn(&self) -> (char, PlayerMessage) {
-        if self.video.paused() {
-            (icons::PLAY, PlayerMessage::TogglePlay)
-        } else if self.video.eos() {
-            (icons::REPLAY, PlayerMessage::TogglePlay)
-        } else {
-            (icons::PAUSE, PlayerMessage::TogglePlay)
+            for (x, y) in [
+                (-1.0, -1.0),
+                (0.0, -1.0),
+                (1.0, -1.0),
+                (-1.0, 0.0),
+                (1.0, 0.0),
+                (-1.0, 1.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+            ] {
+                layers.push(line(color, x * outline, y * outline));
+            }
         }
+        layers.push(line(style.color(), 0.0, 0.0));
+
+        container(stack(layers))
+            .padding([4, 10])
+            .style(move |_| container::Style {
+                background: (background > 0.0).then(|| Color::BLACK.scale_alpha(background).into()),
+                border: Border::default().rounded(4.0),
+                ..Default::default()
+            })
+            .into()
     }
 
-    fn top(&self) -> Element<'_, PlayerMessage> {
-        let title = text(self.name());
-        let options = column!(
+    fn adjustments_panel(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let current = self.adjustments;
+
+        let adjustment = |label: String,
+                          range: std::ops::RangeInclusive<f32>,
+                          value: f32,
+                          set: fn(Adjustments, f32) -> Adjustments| {
             row!(
-                text_button(icons::ADD_COLLECTION).on_press(PlayerMessage::AddCollection),
-                text_button(icons::VIDEO_CONFIG).on_press(PlayerMessage::Config)
+                text(label).size(size).width(80.0),
+                slider(range, value, move |value| {
+                    PlayerMessage::Adjust(AdjustMessage::Set(set(current, value)))
+                })
+                .step(0.01),
+                text(format!("{value:.2}")).size(size).width(36.0)
             )
-            .spacing(6.0)
+            .spacing(8.0)
             .align_y(Vertical::Center)
+        };
+
+        let small = |label: String, message: AdjustMessage| {
+            button(text(label).size(size))
+                .padding([3, 10])
+                .style(button::secondary)
+                .on_press(PlayerMessage::Adjust(message))
+        };
+
+        let content = column!(
+            text(t!("adjust-title")).size(h6()),
+            adjustment(
+                t!("adjust-brightness"),
+                Adjustments::BRIGHTNESS,
+                current.brightness,
+                |adjustments, brightness| Adjustments {
+                    brightness,
+                    ..adjustments
+                }
+            ),
+            adjustment(
+                t!("adjust-contrast"),
+                Adjustments::CONTRAST,
+                current.contrast,
+                |adjustments, contrast| Adjustments {
+                    contrast,
+                    ..adjustments
+                }
+            ),
+            adjustment(
+                t!("adjust-saturation"),
+                Adjustments::SATURATION,
+                current.saturation,
+                |adjustments, saturation| Adjustments {
+                    saturation,
+                    ..adjustments
+                }
+            ),
+            adjustment(
+                t!("adjust-hue"),
+                Adjustments::HUE,
+                current.hue,
+                |adjustments, hue| Adjustments { hue, ..adjustments }
+            ),
+            adjustment(
+                t!("adjust-gamma"),
+                Adjustments::GAMMA,
+                current.gamma,
+                |adjustments, gamma| Adjustments {
+                    gamma,
+                    ..adjustments
+                }
+            ),
+            row!(
+                small(t!("common-reset"), AdjustMessage::Reset),
+                horizontal_space(),
+                small(t!("adjust-save-video"), AdjustMessage::SaveForVideo),
+                small(t!("adjust-save-all"), AdjustMessage::SaveGlobally),
+            )
+            .spacing(6.0),
+            self.framing_section(),
+            self.audio_section(),
+            row!(
+                horizontal_space(),
+                button(text(t!("common-close")).size(size))
+                    .padding([3, 10])
+                    .style(button::text)
+                    .on_press(PlayerMessage::Config)
+            ),
         )
-        .align_x(Horizontal::Right)
-        .width(Self::WIDTH);
-        let back = container(text_button(icons::BACK).on_press(PlayerMessage::PreviousScreen))
-            .align_x(Horizontal::Left)
-            .align_y(Vertical::Center)
-            .width(Self::WIDTH);
+        .spacing(10.0)
+        .width(360.0);
 
-        let content = row!(back, horizontal_space(), title, horizontal_space(), options)
-            .width(Length::Fill)
-            .align_y(Vertical::Center);
+        let panel = container(scrollable(content))
+            .padding(12)
+            .style(theme::panel);
 
-        let content: Element<'_, PlayerMessage> = if self.show_controls {
-            content.into()
-        } else {
-            horizontal_space().height(35).into()
+        container(panel).center(Length::Fill).into()
+    }
+
+    fn audio_section(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let filters = self.settings.audio;
+
+        let toggle = |label: String, on: bool, message: fn(bool) -> AudioMessage| {
+            row!(
+                text(label).size(size),
+                horizontal_space(),
+                button(
+                    text(if on {
+                        t!("common-on")
+                    } else {
+                        t!("common-off")
+                    })
+                    .size(size)
+                )
+                .padding([3, 10])
+                .style(button::secondary)
+                .on_press(PlayerMessage::Audio(message(!on)))
+            )
+            .align_y(Vertical::Center)
         };
 
-        let content = mouse_area(content)
-            .on_exit(PlayerMessage::CursorExit)
-            .on_enter(PlayerMessage::CursorEnter);
+        let delay = self.config.audio_delay;
+        let delay_button = |label: String, message: PlayerMessage| {
+            button(text(label).size(size))
+                .padding([3, 10])
+                .style(button::secondary)
+                .on_press(message)
+        };
 
-        content.into()
+        let preset = pick_list(EqualizerPreset::ALL, Some(filters.preset), |preset| {
+            PlayerMessage::Audio(AudioMessage::Preset(preset))
+        })
+        .text_size(size);
+
+        let bands = row(filters.bands.iter().zip(audio::BANDS).enumerate().map(
+            |(index, (gain, label))| {
+                column!(
+                    vertical_slider(AudioFilters::BAND, *gain, move |gain| {
+                        PlayerMessage::Audio(AudioMessage::Band(index, gain))
+                    })
+                    .step(0.5)
+                    .height(100.0)
+                    .on_release(PlayerMessage::Audio(AudioMessage::Save)),
+                    text(label).size(10.0)
+                )
+                .spacing(4.0)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill)
+                .into()
+            },
+        ));
+
+        column!(
+            text(t!("audio-title")).size(h6()),
+            toggle(
+                t!("audio-night-mode"),
+                filters.night_mode,
+                AudioMessage::NightMode
+            ),
+            toggle(
+                t!("audio-normalize"),
+                filters.normalize,
+                AudioMessage::Normalize
+            ),
+            row!(text(t!("audio-equalizer")).size(size).width(80.0), preset)
+                .spacing(8.0)
+                .align_y(Vertical::Center),
+            bands,
+            row!(
+                text(t!("audio-delay", delay = format!("{delay:+}"))).size(size),
+                horizontal_space(),
+                delay_button(
+                    "-".to_owned(),
+                    PlayerMessage::AudioDelay(-Self::AUDIO_DELAY_STEP)
+                ),
+                delay_button(
+                    "+".to_owned(),
+                    PlayerMessage::AudioDelay(Self::AUDIO_DELAY_STEP)
+                ),
+                delay_button(t!("common-reset"), PlayerMessage::AudioDelay(-delay)),
+            )
+            .spacing(6.0)
+            .align_y(Vertical::Center),
+        )
+        .spacing(10.0)
+        .into()
     }
 
-    fn media_controls(&self) -> Element<'_, PlayerMessage> {
-        let left = {
-            let volume = slider(0.0..=1.0, self.volume, PlayerMessage::ChangeVolume)
-                .step(0.05)
-                .shift_step(0.1)
-                .width(125.0);
+    fn framing_section(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let framing = self.framing();
+        let crop = framing.crop;
+
+        let aspect = pick_list(AspectRatio::PRESETS, Some(framing.aspect), |aspect| {
+            PlayerMessage::Frame(FrameMessage::Aspect(aspect))
+        })
+        .text_size(size);
+        let custom = text_input(&t!("framing-custom-aspect"), &self.custom_aspect)
+            .on_input(|input| PlayerMessage::Frame(FrameMessage::CustomAspect(input)))
+            .on_submit(PlayerMessage::Frame(FrameMessage::Save))
+            .size(size);
+        let fit = pick_list(Fit::ALL, Some(framing.fit), |fit| {
+            PlayerMessage::Frame(FrameMessage::Fit(fit))
+        })
+        .text_size(size);
+
+        let edge = |label: String, value: f32, set: fn(Crop, f32) -> Crop| {
             row!(
-                text_button(icons::SUBTITLES).on_press(PlayerMessage::ToggleSubtitles),
-                text_button(if self.video.muted() {
-                    icons::MUTE
-                } else {
-                    icons::VOLUME
+                text(label).size(size).width(80.0),
+                slider(0.0..=Crop::MAX, value, move |value| {
+                    PlayerMessage::Frame(FrameMessage::Crop(set(crop, value)))
                 })
-                .on_press(PlayerMessage::ToggleMute),
-                volume
+                .step(0.005)
+                .on_release(PlayerMessage::Frame(FrameMessage::Save)),
+                text(format!("{:.0}%", value * 100.0))
+                    .size(size)
+                    .width(36.0)
             )
-            .spacing(2.0)
+            .spacing(8.0)
             .align_y(Vertical::Center)
-        }
-        .width(Self::WIDTH);
+        };
 
-        let middle = {
-            let (play, message) = self.play_btn();
+        let small = |label: String, message: FrameMessage| {
+            button(text(label).size(size))
+                .padding([3, 10])
+                .style(button::secondary)
+                .on_press(PlayerMessage::Frame(message))
+        };
 
+        column!(
+            text(t!("framing-title")).size(h6()),
             row!(
-                text_button(icons::PREVIOUS_VIDEO)
-                    .on_press_maybe(self.has_previous.then_some(PlayerMessage::PlayPrevious)),
-                text_button(icons::SEEK_BACK).on_press(PlayerMessage::SeekBack(false)),
-                text_button(play).on_press(message),
-                text_button(icons::SEEK_FRONT).on_press(PlayerMessage::SeekFront(false)),
-                text_button(icons::NEXT_VIDEO)
-                    .on_press_maybe(self.has_next.then_some(PlayerMessage::PlayNext))
+                text(t!("framing-aspect")).size(size).width(80.0),
+                aspect,
+                custom
             )
-            .spacing(2.0)
+            .spacing(8.0)
+            .align_y(Vertical::Center),
+            row!(text(t!("framing-size")).size(size).width(80.0), fit)
+                .spacing(8.0)
+                .align_y(Vertical::Center),
+            row!(
+                text(t!(
+                    "framing-zoom",
+                    zoom = format!("{:.0}", framing.zoom * 100.0)
+                ))
+                .size(size),
+                horizontal_space(),
+                small(t!("framing-reset-zoom"), FrameMessage::ResetZoom)
+            )
+            .align_y(Vertical::Center),
+            edge(t!("framing-crop-left"), crop.left, |crop, left| Crop {
+                left,
+                ..crop
+            }),
+            edge(t!("framing-crop-top"), crop.top, |crop, top| Crop {
+                top,
+                ..crop
+            }),
+            edge(t!("framing-crop-right"), crop.right, |crop, right| Crop {
+                right,
+                ..crop
+            }),
+            edge(t!("framing-crop-bottom"), crop.bottom, |crop, bottom| {
+                Crop { bottom, ..crop }
+            }),
+            row!(
+                text(t!("framing-crop-black-bars")).size(size),
+                horizontal_space(),
+                small(
+                    if self.settings.auto_crop {
+                        t!("common-on")
+                    } else {
+                        t!("common-off")
+                    },
+                    FrameMessage::AutoCrop(!self.settings.auto_crop)
+                ),
+                small(t!("framing-reset-crop"), FrameMessage::ResetCrop)
+            )
+            .spacing(6.0)
+            .align_y(Vertical::Center),
+        )
+        .spacing(10.0)
+        .into()
+    }
+
+    fn export_panel(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+        let (start, end) = self.clip();
+
+        let small = |label: String, message: ExportMessage| {
+            button(text(label).size(size))
+                .padding([2, 8])
+                .style(button::secondary)
+                .on_press(PlayerMessage::Export(message))
+        };
+
+        let range = row!(
+            text(t!(
+                "export-range",
+                start = timestamp(start),
+                end = timestamp(end),
+                length = format!("{:.1}", (end - start).max(0.0))
+            ))
+            .size(size)
+            .width(Length::Fill),
+            small(t!("export-in"), ExportMessage::SetIn),
+            small(t!("export-out"), ExportMessage::SetOut),
+            small(t!("common-clear"), ExportMessage::Clear),
+        )
+        .spacing(4.0)
+        .align_y(Vertical::Center);
+
+        let format = pick_list(export::Format::ALL, Some(self.export_format), |format| {
+            PlayerMessage::Export(ExportMessage::Format(format))
+        })
+        .text_size(size)
+        .width(Length::Fill);
+
+        let mut content = vec![
+            text(t!("export-title")).size(h6()).into(),
+            range.into(),
+            format.into(),
+        ];
+
+        if self.export_format.is_animation() {
+            let width = pick_list(Self::EXPORT_WIDTHS, Some(self.export_width), |width| {
+                PlayerMessage::Export(ExportMessage::Width(width))
+            })
+            .text_size(size);
+            let fps = pick_list(Self::EXPORT_FPS, Some(self.export_fps), |fps| {
+                PlayerMessage::Export(ExportMessage::Fps(fps))
+            })
+            .text_size(size);
+
+            content.push(
+                row!(
+                    text(t!("export-width")).size(size),
+                    width,
+                    horizontal_space(),
+                    text(t!("export-fps")).size(size),
+                    fps
+                )
+                .spacing(6.0)
+                .align_y(Vertical::Center)
+                .into(),
+            );
+        }
+
+        let actions: Element<'_, PlayerMessage> = match self.export_job.as_ref() {
+            Some(job) => row!(
+                progress_bar(0.0..=1.0, job.progress).girth(8.0),
+                small(t!("common-cancel"), ExportMessage::Cancel)
+            )
+            .spacing(8.0)
             .align_y(Vertical::Center)
+            .into(),
+            None => row!(
+                horizontal_space(),
+                button(text(t!("common-close")).size(size))
+                    .padding([3, 10])
+                    .style(button::text)
+                    .on_press(PlayerMessage::Export(ExportMessage::Toggle)),
+                button(text(t!("export-start")).size(size))
+                    .padding([3, 10])
+                    .on_press_maybe(
+                        (end > start).then_some(PlayerMessage::Export(ExportMessage::Start))
+                    )
+            )
+            .spacing(6.0)
+            .into(),
         };
+        content.push(actions);
 
-        let right = column!(
-            row!(
-                text_button(icons::FAVORITE).on_press(PlayerMessage::Favorite),
-                text_button(icons::COMMENT).on_press(PlayerMessage::Comment),
-                text_button(if self.is_fullscreen {
-                    icons::MINIMIZE
+        let panel = container(column(content).spacing(10.0).width(360.0))
+            .padding(12)
+            .style(theme::panel);
+
+        container(panel).center(Length::Fill).into()
+    }
+
+    fn toast(&self) -> Option<Element<'_, PlayerMessage>> {
+        let toast = self.toast.as_ref()?;
+        let size = h7();
+
+        let mut actions = vec![];
+
+        if let Some(path) = toast.screenshot.as_ref() {
+            actions.push(Element::from(
+                button(text(t!("export-poster")).size(size))
+                    .padding([3, 8])
+                    .style(button::secondary)
+                    .on_press(PlayerMessage::UseAsPoster(path.clone())),
+            ));
+        }
+        actions.push(
+            sized_button(icons::CANCEL, size)
+                .on_press(PlayerMessage::DismissToast)
+                .into(),
+        );
+
+        let content = column!(
+            text(toast.message.as_str()).size(size),
+            row(actions).spacing(6.0).align_y(Vertical::Center)
+        )
+        .spacing(6.0)
+        .width(320.0);
+
+        let toast = container(content).padding(10).style(theme::panel);
+
+        Some(
+            column!(vertical_space(), row!(horizontal_space(), toast))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(Padding::new(12.0).bottom(120.0))
+                .into(),
+        )
+    }
+
+    fn osd(&self) -> Option<Element<'_, PlayerMessage>> {
+        let (message, _) = self.osd.as_ref()?;
+
+        let osd = container(text(message.as_str()).size(h6()).color(Color::WHITE))
+            .padding([6, 12])
+            .style(|_| container::Style {
+                background: Some(Color::BLACK.scale_alpha(0.6).into()),
+                border: Border::default().rounded(4.0),
+                ..Default::default()
+            });
+
+        Some(
+            container(center_x(osd))
+                .width(Length::Fill)
+                .padding(Padding::ZERO.top(70.0))
+                .into(),
+        )
+    }
+
+    fn queue_panel(&self) -> Element<'_, PlayerMessage> {
+        let size = h7();
+
+        let header = {
+            let shuffle = sized_button(icons::RAND, size)
+                .style(if self.queue.is_shuffled() {
+                    button::secondary
                 } else {
-                    icons::MAXIMIZE
+                    button::text
                 })
-                .on_press(PlayerMessage::ToggleFullscreen)
+                .on_press(PlayerMessage::Queue(QueueMessage::Shuffle));
+
+            let repeat = button(
+                row!(
+                    self.queue.repeat.icon().size(size),
+                    text(self.queue.repeat.to_string()).size(size)
+                )
+                .spacing(4.0)
+                .align_y(Vertical::Center),
             )
-            .spacing(2.0)
+            .style(if matches!(self.queue.repeat, Repeat::Off) {
+                button::text
+            } else {
+                button::secondary
+            })
+            .on_press(PlayerMessage::Queue(QueueMessage::Repeat));
+
+            row!(
+                text(t!("queue-title", count = self.queue.len())).size(h6()),
+                horizontal_space(),
+                shuffle,
+                repeat
+            )
+            .spacing(4.0)
             .align_y(Vertical::Center)
-        )
-        .align_x(Horizontal::Right)
-        .width(Self::WIDTH);
+        };
 
-        let content = row!(left, horizontal_space(), middle, horizontal_space(), right)
-            .width(Length::Fill)
-            .align_y(Vertical::Center);
+        let len = self.queue.len();
+        let current = self.queue.position();
 
-        let timeline = {
-            let duration = self.video.duration();
-            let spent = format!(
-                "{:02}:{:02}:{:02}",
-                self.position as u64 / 3600,
-                self.position as u64 / 60,
-                self.position as u64 % 60,
-            );
-            let total = format!(
-                "{:02}:{:02}:{:02}",
-                duration.as_secs() / 3600,
-                duration.as_secs() / 60,
-                duration.as_secs() % 60,
-            );
+        let items = self.queue.iter().enumerate().map(|(i, video)| {
+            let is_current = i == current;
 
-            let slider = widgets::slider::VideoSlider::new(
-                0.0..=duration.as_secs_f64(),
-                self.position,
-                PlayerMessage::Seek,
-                self.thumbnails.clone(),
-                Font::default(),
-                duration,
+            let item = button(text(&video.name).size(size))
+                .width(Length::Fill)
+                .padding([3, 6])
+                .style(if is_current {
+                    button::secondary
+                } else {
+                    button::text
+                })
+                .on_press(PlayerMessage::Queue(QueueMessage::Jump(i)));
+
+            let controls = row!(
+                sized_button(icons::CHEV_UP, size).on_press_maybe(
+                    (i > 0).then_some(PlayerMessage::Queue(QueueMessage::MoveUp(i)))
+                ),
+                sized_button(icons::CHEV_DOWN, size).on_press_maybe(
+                    (i + 1 < len).then_some(PlayerMessage::Queue(QueueMessage::MoveDown(i)))
+                ),
+                sized_button(icons::CANCEL, size).on_press_maybe(
+                    (!is_current).then_some(PlayerMessage::Queue(QueueMessage::Remove(i)))
+                ),
             )
-            .step(0.1)
-            .on_release(PlayerMessage::SeekRelease);
+            .spacing(2.0)
+            .align_y(Vertical::Center);
 
-            row!(text(spent), slider, text(total))
-                .spacing(20.0)
-                .align_y(Vertical::Center)
-                .width(Length::Fill)
-        };
+            Element::from(row!(item, controls).spacing(4.0).align_y(Vertical::Center))
+        });
 
-        let content = column!(timeline, content, vertical_space().height(8.0))
-            .spacing(8)
-            .width(Length::Fill);
+        let items = scrollable(column(items).spacing(4.0)).spacing(4.0);
 
-        let content: Element<'_, PlayerMessage> = if self.show_controls {
-            content.into()
-        } else {
-            horizontal_space().height(75).into()
+        container(column!(header, items).spacing(8.0))
+            .padding(8)
+            .width(320.0)
+            .height(Length::Fill)
+            .style(theme::panel)
+            .into()
+    }
+
+    fn up_next_card<'a>(&'a self, up_next: &'a UpNext) -> Element<'a, PlayerMessage> {
+        let Some(next) = self.queue.peek_next() else {
+            return utils::empty();
         };
 
-        let content = mouse_area(content)
-            .on_exit(PlayerMessage::CursorExit)
-            .on_enter(PlayerMessage::CursorEnter);
+        let (width, height) = (120.0, 180.0);
+        let poster: Element<'_, PlayerMessage> = match &up_next.poster {
+            Some(handle) => image(handle)
+                .width(width)
+                .height(height)
+                .content_fit(ContentFit::Cover)
+                .into(),
+            None => container(utils::empty())
+                .width(width)
+                .height(height)
+                .style(theme::card)
+                .into(),
+        };
 
-        content.into()
+        let details = column!(
+            text(t!("queue-up-next", seconds = up_next.remaining)).size(h7()),
+            text(&next.name).size(h5()),
+            vertical_space(),
+            row!(
+                button(text(t!("queue-play-now")).size(h7()))
+                    .padding([6, 12])
+                    .on_press(PlayerMessage::PlayNow),
+                button(text(t!("common-cancel")).size(h7()))
+                    .padding([6, 12])
+                    .style(button::text)
+                    .on_press(PlayerMessage::CancelNext),
+            )
+            .spacing(8.0)
+            .align_y(Vertical::Center)
+        )
+        .spacing(8.0)
+        .height(height)
+        .width(220.0);
+
+        container(row!(poster, details).spacing(12.0))
+            .padding(12)
+            .style(theme::panel)
+            .into()
     }
 
     fn video_elem(&self) -> Element<'_, PlayerMessage> {
+        let framing = self.framing();
+
+        if framing.is_default() {
+            return container(self.video_player(Length::Fill, Length::Fill, ContentFit::Contain))
+                .align_x(iced::Alignment::Center)
+                .align_y(iced::Alignment::Center)
+                .width(iced::Length::Fill)
+                .height(iced::Length::Fill)
+                .into();
+        }
+
+        let source = self.source_size();
+
+        responsive(move |viewport| {
+            let frame = framing::frame(&framing, source, viewport);
+            let video = self.video_player(
+                Length::Fixed(frame.width),
+                Length::Fixed(frame.height),
+                ContentFit::Fill,
+            );
+
+            container(pin(video).x(frame.x).y(frame.y))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .clip(true)
+                .into()
+        })
+        .into()
+    }
+
+    fn video_player(
+        &self,
+        width: Length,
+        height: Length,
+        content_fit: ContentFit,
+    ) -> Element<'_, PlayerMessage> {
         let play = self.play_btn();
         let fullscreen = video_icon(if self.is_fullscreen {
             icons::MINIMIZE
@@ -488,42 +3356,84 @@ impl Player {
             size: Some(24.0.into()),
             ..fullscreen
         };
-        let video = container(
-            VideoPlayer::new(&self.video)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .play_icon(video_icon(play.0), play.1)
-                .next_icon(video_icon(icons::NEXT_VIDEO), PlayerMessage::PlayNext)
-                .previous_icon(
-                    video_icon(icons::PREVIOUS_VIDEO),
-                    PlayerMessage::PlayPrevious,
-                )
-                .fullscreen_icon(fullscreen, PlayerMessage::ToggleFullscreen)
-                .on_keypress(handle_keypress)
-                .on_click(handle_clicks)
-                .enable_overlay(self.is_fullscreen && !self.show_controls)
-                .content_fit(iced::ContentFit::Contain)
-                .on_end_of_stream(PlayerMessage::EndOfStream)
-                .on_new_frame(PlayerMessage::NewFrame),
-        )
-        .align_x(iced::Alignment::Center)
-        .align_y(iced::Alignment::Center)
-        .width(iced::Length::Fill)
-        .height(iced::Length::Fill);
 
-        video.into()
+        VideoPlayer::new(&self.video)
+            .width(width)
+            .height(height)
+            .play_icon(video_icon(play.0), play.1)
+            .next_icon(video_icon(icons::NEXT_VIDEO), PlayerMessage::PlayNext)
+            .previous_icon(
+                video_icon(icons::PREVIOUS_VIDEO),
+                PlayerMessage::PlayPrevious,
+            )
+            .fullscreen_icon(fullscreen, PlayerMessage::ToggleFullscreen)
+            .on_keypress(handle_keypress)
+            .on_click(handle_clicks)
+            .enable_overlay(self.is_fullscreen && !self.show_controls)
+            .content_fit(content_fit)
+            .on_end_of_stream(PlayerMessage::EndOfStream)
+            .on_subtitle_text(PlayerMessage::SubtitleText)
+            .on_new_frame(PlayerMessage::NewFrame)
+            .into()
     }
 
     pub fn view(&self) -> Element<'_, PlayerMessage> {
-        let content = stack!(
-            self.video_elem(),
-            column!(self.top(), vertical_space(), self.media_controls())
+        let mut layers = vec![self.video_elem()];
+
+        if self.show_subtitles && (self.cue.is_some() || self.secondary_cue.is_some()) {
+            layers.push(responsive(move |size| self.subtitles_layer(size)).into());
+        }
+
+        if let Some(osd) = self.osd() {
+            layers.push(osd);
+        }
+
+        if self.show_export {
+            layers.push(self.export_panel());
+        }
+
+        if self.show_adjustments {
+            layers.push(self.adjustments_panel());
+        }
+
+        if let Some(toast) = self.toast() {
+            layers.push(toast);
+        }
+
+        layers.push(
+            column!(
+                self.top(),
+                if self.show_queue {
+                    Element::from(
+                        row!(horizontal_space(), self.queue_panel())
+                            .height(Length::Fill)
+                            .padding([6, 0]),
+                    )
+                } else {
+                    vertical_space().into()
+                },
+                self.media_controls()
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding([3, 6])
+            .into(),
+        );
+
+        if let Some(up_next) = self.up_next.as_ref() {
+            layers.push(
+                column!(
+                    vertical_space(),
+                    row!(horizontal_space(), self.up_next_card(up_next))
+                )
                 .width(Length::Fill)
                 .height(Length::Fill)
-                .padding([3, 6])
-        )
-        .height(Length::Fill)
-        .width(Length::Fill);
+                .padding(iced::Padding::new(12.0).bottom(120.0))
+                .into(),
+            );
+        }
+
+        let content = stack(layers).height(Length::Fill).width(Length::Fill);
 
         let content = container(content)
             .width(Length::Fill)
@@ -537,39 +3447,44 @@ impl Player {
     }
 }
 
+/// Formats `secs` as `hh:mm:ss.s`.
+fn timestamp(secs: f64) -> String {
+    let tenths = (secs.max(0.0) * 10.0).round() as u64;
+    let secs = tenths / 10;
+
+    format!(
+        "{:02}:{:02}:{:02}.{}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+        tenths % 10
+    )
+}
+
 fn video_icon(unicode: char) -> Icon<iced::Font> {
     Icon {
         code_point: unicode,
         font: icons::FONT,
-        size: Some(52.0.into()),
+        size: Some((52.0 * scale()).into()),
         color: None,
     }
 }
 
 fn handle_keypress(keypress: KeyPress) -> PlayerMessage {
-    use key::{Key, Named};
-
-    match keypress.key {
-        Key::Named(Named::Space) => PlayerMessage::TogglePlay,
-        Key::Named(Named::Enter) => PlayerMessage::ToggleFullscreen,
-        Key::Named(Named::Escape) => PlayerMessage::ExitFullscreen,
-        Key::Named(Named::ArrowLeft) => PlayerMessage::SeekBack(keypress.modifiers.shift()),
-        Key::Named(Named::ArrowRight) => PlayerMessage::SeekFront(keypress.modifiers.shift()),
-        Key::Named(Named::ArrowUp) => PlayerMessage::IncrVolume,
-        Key::Named(Named::ArrowDown) => PlayerMessage::DecrVolume,
-        Key::Character(char) if char.as_str() == "f" => PlayerMessage::ToggleFullscreen,
-        Key::Character(char) if char.as_str() == "c" => PlayerMessage::IncrSpeed,
-        Key::Character(char) if char.as_str() == "x" => PlayerMessage::DecrSpeed,
-        Key::Character(char) if char.as_str() == "z" => PlayerMessage::ResetSpeed,
-        _ => PlayerMessage::None,
+    match Chord::from_key(&keypress.key, keypress.modifiers) {
+        Some(chord) => PlayerMessage::Chord(chord),
+        None => PlayerMessage::None,
     }
 }
 
 fn handle_clicks(click: MouseClick) -> PlayerMessage {
-    match click.button {
-        Button::Left if matches!(click.kind, Kind::Single) => PlayerMessage::TogglePlay,
-        Button::Left if matches!(click.kind, Kind::Double) => PlayerMessage::ToggleFullscreen,
-        Button::Right => PlayerMessage::Config,
-        _ => PlayerMessage::None,
-    }
+    let name = match click.button {
+        Button::Left if matches!(click.kind, Kind::Single) => "Click",
+        Button::Left if matches!(click.kind, Kind::Double) => "DoubleClick",
+        Button::Right => "RightClick",
+        Button::Middle => "MiddleClick",
+        _ => return PlayerMessage::None,
+    };
+
+    PlayerMessage::Chord(Chord::click(name))
 }
@@ -0,0 +1,44 @@
+//! Brightness, contrast, saturation and hue of the picture.
+use gstreamer::{self as gst, prelude::*};
+use gstreamer_video::{self as gst_video, prelude::*};
+use std::ops::RangeInclusive;
+
+use crate::config::Adjustments;
+
+/// Applies the colour balance of `adjustments` to the playbin `pipeline`.
+///
+/// The playbin balances colours in the video sink when it can and inserts a
+/// `videobalance` element otherwise. Gamma is applied by the renderer instead.
+pub fn apply(pipeline: &gst::Pipeline, adjustments: &Adjustments) {
+    let Some(balance) = pipeline.dynamic_cast_ref::<gst_video::ColorBalance>() else {
+        return;
+    };
+
+    for channel in balance.list_channels() {
+        let label = channel.label().to_uppercase();
+
+        let (value, range) = if label.ends_with("BRIGHTNESS") {
+            (adjustments.brightness, Adjustments::BRIGHTNESS)
+        } else if label.ends_with("CONTRAST") {
+            (adjustments.contrast, Adjustments::CONTRAST)
+        } else if label.ends_with("SATURATION") {
+            (adjustments.saturation, Adjustments::SATURATION)
+        } else if label.ends_with("HUE") {
+            (adjustments.hue, Adjustments::HUE)
+        } else {
+            continue;
+        };
+
+        let value = scale(value, range, channel.min_value(), channel.max_value());
+        balance.set_value(&channel, value);
+    }
+}
+
+/// Maps `value` linearly from `range` onto the `min..=max` range of a channel,
+/// whose middle is the neutral value.
+fn scale(value: f32, range: RangeInclusive<f32>, min: i32, max: i32) -> i32 {
+    let (start, end) = range.into_inner();
+    let fraction = ((value - start) / (end - start)).clamp(0.0, 1.0) as f64;
+
+    (min as f64 + fraction * (max - min) as f64).round() as i32
+}
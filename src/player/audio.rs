@@ -0,0 +1,110 @@
+//! Volume boost, night mode compression, normalization and equalization of
+//! the sound.
+use gstreamer::{self as gst, prelude::*};
+
+use crate::config::AudioFilters;
+use crate::error::*;
+
+/// The labels of the equalizer bands, by their centre frequency in Hz.
+pub const BANDS: [&str; 10] = [
+    "29", "59", "119", "237", "474", "947", "1.9k", "3.8k", "7.5k", "15k",
+];
+
+/// The filter placed before the audio sink. Gain comes first so the
+/// compressors catch what it pushes too high, and the limiter comes last.
+const FILTER: &str = "audioconvert ! volume name=gain \
+    ! audiodynamic name=night characteristics=soft-knee mode=compressor threshold=1.0 ratio=1.0 \
+    ! equalizer-10bands name=equalizer \
+    ! audiodynamic name=limiter characteristics=soft-knee mode=compressor threshold=0.9 ratio=0.1 \
+    ! audioconvert";
+
+/// Gain added before compressing in night mode, so quiet speech is heard.
+const NIGHT_GAIN: f64 = 1.6;
+
+/// Places the audio filter into the playbin `pipeline`.
+///
+/// The playbin only links a new filter when it starts over, so a pipeline
+/// which has already started is taken back to the ready state. This waits
+/// until it is back where it was, so its streams are known on return.
+pub fn install(pipeline: &gst::Pipeline) -> Result<()> {
+    let filter = gst::parse::bin_from_description(FILTER, true).map_err(GStreamerError::Glib)?;
+    let (_, state, _) = pipeline.state(gst::ClockTime::ZERO);
+
+    if state <= gst::State::Ready {
+        pipeline.set_property("audio-filter", &filter);
+        return Ok(());
+    }
+
+    pipeline
+        .set_state(gst::State::Ready)
+        .map_err(GStreamerError::StateChangeError)?;
+    pipeline.set_property("audio-filter", &filter);
+    pipeline
+        .set_state(state)
+        .map_err(GStreamerError::StateChangeError)?;
+
+    let (result, _, _) = pipeline.state(gst::ClockTime::from_seconds(5));
+    result.map_err(GStreamerError::StateChangeError)?;
+
+    Ok(())
+}
+
+/// Updates the audio filter of the playbin `pipeline`.
+///
+/// `boost` is the gain above full volume and `replay_gain` the adjustment in
+/// decibels bringing the file to a common loudness.
+pub fn apply(
+    pipeline: &gst::Pipeline,
+    filters: &AudioFilters,
+    boost: f64,
+    replay_gain: Option<f64>,
+) {
+    let Some(filter) = pipeline.property::<Option<gst::Element>>("audio-filter") else {
+        return;
+    };
+    let Some(bin) = filter.downcast_ref::<gst::Bin>() else {
+        return;
+    };
+
+    let normalize = match replay_gain.filter(|_| filters.normalize) {
+        Some(db) => 10f64.powf(db / 20.0),
+        None => 1.0,
+    };
+    let night = if filters.night_mode { NIGHT_GAIN } else { 1.0 };
+
+    if let Some(gain) = bin.by_name("gain") {
+        gain.set_property("volume", (boost * normalize * night).clamp(0.0, 10.0));
+    }
+
+    if let Some(compressor) = bin.by_name("night") {
+        let (threshold, ratio) = if filters.night_mode {
+            (0.3f32, 0.4f32)
+        } else {
+            (1.0, 1.0)
+        };
+        compressor.set_property("threshold", threshold);
+        compressor.set_property("ratio", ratio);
+    }
+
+    if let Some(equalizer) = bin.by_name("equalizer") {
+        for (index, gain) in filters.bands.iter().enumerate() {
+            equalizer.set_property(&format!("band{index}"), *gain as f64);
+        }
+    }
+}
+
+/// The ReplayGain of the audio stream at `index`, in decibels.
+pub fn replay_gain(pipeline: &gst::Pipeline, index: i32) -> Option<f64> {
+    let tags = pipeline.emit_by_name::<Option<gst::TagList>>("get-audio-tags", &[&index])?;
+
+    tags.get::<gst::tags::TrackGain>()
+        .or_else(|| tags.get::<gst::tags::AlbumGain>())
+        .map(|gain| gain.get())
+}
+
+/// Plays the audio `delay` milliseconds after the picture, or ahead of it
+/// when negative.
+pub fn set_delay(pipeline: &gst::Pipeline, delay: i64) {
+    // A positive offset moves the audio ahead of the video.
+    pipeline.set_property("av-offset", -delay * 1_000_000);
+}
@@ -0,0 +1,85 @@
+//! Chapters read from the table of contents of MKV and MP4 files.
+use gstreamer as gst;
+use gstreamer_pbutils as pbutils;
+use std::time::Duration;
+
+use crate::error::*;
+use crate::t;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub title: Option<String>,
+    pub start: Duration,
+}
+
+impl Chapter {
+    /// The title of the chapter, falling back to its number.
+    pub fn label(&self, index: usize) -> String {
+        self.title
+            .clone()
+            .unwrap_or_else(|| t!("player-chapter", number = index + 1))
+    }
+}
+
+/// Reads the chapters of the file at `uri`, sorted by their start.
+pub fn chapters(uri: &str) -> Result<Vec<Chapter>> {
+    gst::init().map_err(GStreamerError::Glib)?;
+
+    let discoverer =
+        pbutils::Discoverer::new(gst::ClockTime::from_seconds(5)).map_err(GStreamerError::Glib)?;
+    let info = discoverer.discover_uri(uri).map_err(GStreamerError::Glib)?;
+
+    let Some(toc) = info.toc() else {
+        return Ok(vec![]);
+    };
+
+    let mut chapters = vec![];
+    collect(&toc.entries(), &mut chapters);
+    chapters.sort_by_key(|chapter| chapter.start);
+
+    Ok(chapters)
+}
+
+/// Chapters may be nested within editions, or within other chapters.
+fn collect(entries: &[gst::TocEntry], chapters: &mut Vec<Chapter>) {
+    for entry in entries {
+        if entry.entry_type() == gst::TocEntryType::Chapter {
+            if let Some((start, _)) = entry.start_stop_times() {
+                let title = entry
+                    .tags()
+                    .and_then(|tags| tags.get::<gst::tags::Title>())
+                    .map(|title| title.get().to_owned());
+
+                chapters.push(Chapter {
+                    title,
+                    start: Duration::from_nanos(start.max(0) as u64),
+                });
+            }
+        }
+
+        collect(&entry.sub_entries(), chapters);
+    }
+}
+
+/// Where the end credits start: the last chapter named after them, within
+/// the second half of a video lasting `duration`.
+pub fn credits(chapters: &[Chapter], duration: Duration) -> Option<Duration> {
+    chapters
+        .iter()
+        .rev()
+        .filter(|chapter| chapter.start >= duration / 2)
+        .find(|chapter| {
+            chapter.title.as_ref().is_some_and(|title| {
+                let title = title.to_lowercase();
+                title.contains("credits") || title.contains("ending")
+            })
+        })
+        .map(|chapter| chapter.start)
+}
+
+/// Returns the index of the chapter playing at `position`.
+pub fn at(chapters: &[Chapter], position: Duration) -> Option<usize> {
+    chapters
+        .partition_point(|chapter| chapter.start <= position)
+        .checked_sub(1)
+}
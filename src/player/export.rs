@@ -0,0 +1,329 @@
+//! Exporting a section of the video as a clip or an animation.
+use glib::object::ObjectExt;
+use gstreamer::{
+    self as gst,
+    prelude::{Cast, ElementExt, ElementExtManual, GstBinExt, PadExt},
+};
+use iced::futures::{SinkExt, Stream, StreamExt, channel::mpsc};
+use std::ffi::OsStr;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::Duration;
+use url::Url;
+
+use crate::error::*;
+use crate::t;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Re-encoded as H.264 video and AAC audio.
+    #[default]
+    Mp4,
+    /// The original streams, cut at the nearest keyframes.
+    Remux,
+    Gif,
+    WebP,
+}
+
+impl Format {
+    pub const ALL: [Self; 4] = [Self::Mp4, Self::Remux, Self::Gif, Self::WebP];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Mp4 => "mp4",
+            Self::Remux => "mkv",
+            Self::Gif => "gif",
+            Self::WebP => "webp",
+        }
+    }
+
+    /// Whether the format is an animation, scaled and resampled on export.
+    pub fn is_animation(&self) -> bool {
+        matches!(self, Self::Gif | Self::WebP)
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mp4 => "MP4 (H.264/AAC)".to_owned(),
+                Self::Remux => t!("export-original"),
+                Self::Gif => t!("export-gif"),
+                Self::WebP => t!("export-webp"),
+            }
+        )
+    }
+}
+
+/// What to export and where to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    /// Where the video is read from, a file or a stream.
+    pub source: Url,
+    pub destination: PathBuf,
+    pub format: Format,
+    pub start: Duration,
+    pub end: Duration,
+    /// The width of animations, keeping the aspect ratio.
+    pub width: u32,
+    /// The frame rate of animations.
+    pub fps: u32,
+    pub has_audio: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Progress {
+    /// The fraction of the section exported so far.
+    Running(f32),
+    Finished(std::result::Result<PathBuf, String>),
+    Cancelled,
+}
+
+/// Runs `job` in the background, reporting its progress until it finishes or
+/// `cancel` is set.
+pub fn run(job: Job, cancel: Arc<AtomicBool>) -> impl Stream<Item = Progress> {
+    iced::stream::channel(16, move |mut output: mpsc::Sender<Progress>| async move {
+        let (sender, mut receiver) = mpsc::unbounded();
+
+        tokio::task::spawn_blocking(move || {
+            let progress = sender.clone();
+            let result = export(&job, &cancel, |fraction| {
+                let _ = progress.unbounded_send(Progress::Running(fraction));
+            });
+
+            let _ = sender.unbounded_send(match result {
+                Ok(true) => Progress::Finished(Ok(job.destination.clone())),
+                Ok(false) => {
+                    let _ = std::fs::remove_file(&job.destination);
+                    Progress::Cancelled
+                }
+                Err(err) => {
+                    let _ = std::fs::remove_file(&job.destination);
+                    Progress::Finished(Err(err.to_string()))
+                }
+            });
+        });
+
+        while let Some(progress) = receiver.next().await {
+            let _ = output.send(progress).await;
+        }
+    })
+}
+
+/// Returns `false` when cancelled.
+fn export(job: &Job, cancel: &AtomicBool, on_progress: impl Fn(f32)) -> Result<bool> {
+    gst::init().map_err(GStreamerError::Glib)?;
+
+    let pipeline = gst::parse::launch(&description(job))
+        .map_err(GStreamerError::Glib)?
+        .downcast::<gst::Pipeline>()
+        .unwrap();
+
+    if matches!(job.format, Format::Remux) {
+        if let (Some(parser), Some(mux)) = (pipeline.by_name("p"), pipeline.by_name("mux")) {
+            select_streams(&pipeline, &parser, &mux, job.has_audio);
+        }
+    }
+
+    let result = run_pipeline(&pipeline, job, cancel, on_progress);
+
+    if let Err(err) = pipeline.set_state(gst::State::Null) {
+        eprintln!("{err}");
+    }
+
+    result
+}
+
+fn run_pipeline(
+    pipeline: &gst::Pipeline,
+    job: &Job,
+    cancel: &AtomicBool,
+    on_progress: impl Fn(f32),
+) -> Result<bool> {
+    pipeline
+        .set_state(gst::State::Paused)
+        .map_err(GStreamerError::StateChangeError)?;
+    pipeline
+        .state(gst::ClockTime::NONE)
+        .0
+        .map_err(GStreamerError::StateChangeError)?;
+
+    // Stream copies can only start on a keyframe.
+    let flags = if matches!(job.format, Format::Remux) {
+        gst::SeekFlags::FLUSH | gst::SeekFlags::KEY_UNIT
+    } else {
+        gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE
+    };
+
+    pipeline
+        .seek(
+            1.0,
+            flags,
+            gst::SeekType::Set,
+            gst::ClockTime::from_nseconds(job.start.as_nanos() as u64),
+            gst::SeekType::Set,
+            gst::ClockTime::from_nseconds(job.end.as_nanos() as u64),
+        )
+        .map_err(GStreamerError::BoolError)?;
+
+    pipeline
+        .set_state(gst::State::Playing)
+        .map_err(GStreamerError::StateChangeError)?;
+
+    let bus = pipeline.bus().unwrap();
+    let length = job
+        .end
+        .saturating_sub(job.start)
+        .as_secs_f32()
+        .max(f32::EPSILON);
+
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+
+        let message = bus.timed_pop_filtered(
+            gst::ClockTime::from_mseconds(250),
+            &[gst::MessageType::Eos, gst::MessageType::Error],
+        );
+
+        match message.as_ref().map(|message| message.view()) {
+            Some(gst::MessageView::Eos(_)) => {
+                on_progress(1.0);
+                return Ok(true);
+            }
+            Some(gst::MessageView::Error(err)) => {
+                return Err(GStreamerError::Glib(err.error()).into());
+            }
+            _ => {}
+        }
+
+        if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+            let done = Duration::from_nanos(position.nseconds()).saturating_sub(job.start);
+            on_progress((done.as_secs_f32() / length).clamp(0.0, 1.0));
+        }
+    }
+}
+
+/// Builds the `gst-launch` style description of the export pipeline.
+///
+/// The streams of a remux are linked by [`select_streams`] once known.
+fn description(job: &Job) -> String {
+    let uri = quote(job.source.as_str());
+    let sink = format!("filesink location={}", quote(&job.destination));
+
+    match job.format {
+        Format::Mp4 => {
+            let audio = if job.has_audio {
+                " d. ! audio/x-raw ! queue ! audioconvert ! audioresample ! avenc_aac ! queue ! mux."
+            } else {
+                ""
+            };
+
+            format!(
+                "uridecodebin uri={uri} name=d \
+                 d. ! video/x-raw ! queue ! videoconvert ! x264enc ! h264parse ! queue ! \
+                 mp4mux name=mux ! {sink}{audio}"
+            )
+        }
+        Format::Remux => {
+            format!("urisourcebin uri={uri} ! parsebin name=p matroskamux name=mux ! {sink}")
+        }
+        Format::Gif | Format::WebP => {
+            let encoder = match job.format {
+                Format::Gif => "gifenc repeat=-1",
+                _ => "webpenc animated=true animation-loops=0",
+            };
+
+            format!(
+                "uridecodebin uri={uri} ! video/x-raw ! videoconvert ! videorate ! videoscale ! \
+                 video/x-raw,width={},pixel-aspect-ratio=1/1,framerate={}/1 ! \
+                 videoconvert ! {encoder} ! {sink}",
+                job.width, job.fps
+            )
+        }
+    }
+}
+
+/// Copies the first video stream out of `parser` into `mux`, along with the
+/// first audio stream when `has_audio`, and drops any others.
+fn select_streams(
+    pipeline: &gst::Pipeline,
+    parser: &gst::Element,
+    mux: &gst::Element,
+    has_audio: bool,
+) {
+    let (pipeline, mux) = (pipeline.downgrade(), mux.downgrade());
+    let (video, audio) = (AtomicBool::new(false), AtomicBool::new(!has_audio));
+
+    parser.connect_pad_added(move |parser, pad| {
+        let (Some(pipeline), Some(mux)) = (pipeline.upgrade(), mux.upgrade()) else {
+            return;
+        };
+
+        let caps = pad.current_caps().unwrap_or_else(|| pad.query_caps(None));
+        let name = caps.structure(0).map(|structure| structure.name().as_str());
+        let wanted = match name {
+            Some(name) if name.starts_with("video/") => !video.swap(true, Ordering::Relaxed),
+            Some(name) if name.starts_with("audio/") => !audio.swap(true, Ordering::Relaxed),
+            _ => false,
+        };
+
+        if let Err(err) = link_stream(&pipeline, pad, wanted.then_some(&mux)) {
+            gst::element_error!(
+                parser,
+                gst::StreamError::Failed,
+                ("Could not copy a stream: {}", err)
+            );
+        }
+    });
+}
+
+/// Links `pad` through a queue into `mux`, or into a sink which throws its
+/// data away when there is none.
+fn link_stream(
+    pipeline: &gst::Pipeline,
+    pad: &gst::Pad,
+    mux: Option<&gst::Element>,
+) -> std::result::Result<(), String> {
+    let element = match mux {
+        Some(_) => gst::ElementFactory::make("queue").build(),
+        None => gst::ElementFactory::make("fakesink")
+            .property("sync", false)
+            .property("async", false)
+            .build(),
+    }
+    .map_err(|err| err.to_string())?;
+
+    pipeline.add(&element).map_err(|err| err.to_string())?;
+    if let Some(mux) = mux {
+        element.link(mux).map_err(|err| err.to_string())?;
+    }
+    element
+        .sync_state_with_parent()
+        .map_err(|err| err.to_string())?;
+
+    let sink = element.static_pad("sink").unwrap();
+    pad.link(&sink).map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+/// Quotes `value`, such as a path, for a pipeline description.
+pub fn quote(value: impl AsRef<OsStr>) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .as_ref()
+            .to_string_lossy()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+    )
+}
@@ -0,0 +1,71 @@
+//! Placing the picture in the window for aspect ratios, zoom and crop.
+use iced::{Rectangle, Size, Vector};
+
+use crate::config::{Fit, Framing};
+
+/// Where the whole picture of a video of `source` pixels is drawn within the
+/// `viewport`. Parts of it may fall outside, to be clipped.
+pub fn frame(framing: &Framing, source: Size, viewport: Size) -> Rectangle {
+    let (scale, region) = scale(framing, source, viewport);
+    let crop = &framing.crop;
+    let overflow = overflow(framing, source, viewport);
+
+    // The shown part of the picture is centred, then panned.
+    let x = (viewport.width - region.width) / 2.0 + framing.pan_x * overflow.x / 2.0;
+    let y = (viewport.height - region.height) / 2.0 + framing.pan_y * overflow.y / 2.0;
+
+    Rectangle {
+        x: x - crop.left * scale.width,
+        y: y - crop.top * scale.height,
+        width: scale.width,
+        height: scale.height,
+    }
+}
+
+/// How much the shown part of the picture exceeds the `viewport`.
+pub fn overflow(framing: &Framing, source: Size, viewport: Size) -> Vector {
+    let (_, region) = scale(framing, source, viewport);
+
+    Vector::new(
+        (region.width - viewport.width).max(0.0),
+        (region.height - viewport.height).max(0.0),
+    )
+}
+
+/// Returns the size of the whole picture and of its uncropped part.
+fn scale(framing: &Framing, source: Size, viewport: Size) -> (Size, Size) {
+    let crop = &framing.crop;
+    let aspect = framing
+        .aspect
+        .ratio()
+        .unwrap_or(source.width / source.height.max(1.0));
+
+    // The uncropped part, for a picture one unit high.
+    let region = Size::new(
+        aspect * (1.0 - crop.left - crop.right).max(0.01),
+        (1.0 - crop.top - crop.bottom).max(0.01),
+    );
+
+    let (width, height) = match framing.fit {
+        Fit::Contain => {
+            let scale = (viewport.width / region.width).min(viewport.height / region.height);
+            (scale, scale)
+        }
+        Fit::Cover => {
+            let scale = (viewport.width / region.width).max(viewport.height / region.height);
+            (scale, scale)
+        }
+        Fit::Stretch => (
+            viewport.width / region.width,
+            viewport.height / region.height,
+        ),
+        Fit::Original => (source.height, source.height),
+    };
+
+    let (width, height) = (width * framing.zoom, height * framing.zoom);
+
+    (
+        Size::new(aspect * width, height),
+        Size::new(region.width * width, region.height * height),
+    )
+}
@@ -0,0 +1,81 @@
+//! Finding black bars burnt into the picture.
+use gstreamer as gst;
+use std::path::Path;
+
+use crate::config::Crop;
+use crate::utils::ThumbnailGenerator;
+
+/// Frames looked at, spread over the video.
+const SAMPLES: u32 = 8;
+/// The brightest a pixel of a black bar may be. Video black is 16.
+const BLACK: u8 = 32;
+/// The share of pixels in a row or column allowed to be brighter, for noise
+/// and logos.
+const TOLERANCE: f32 = 0.02;
+/// Bars thinner than this fraction of the picture are left alone.
+const MIN_BAR: f32 = 0.01;
+
+/// Samples frames of the video at `path` for borders which stay black
+/// throughout, returning the crop removing them.
+pub fn detect(path: impl AsRef<Path>, width: i32, height: i32) -> Crop {
+    let generator = ThumbnailGenerator::new(path, width, height, 1);
+    let duration = generator.duration.seconds_f64();
+    let (width, height) = (width as usize, height as usize);
+
+    // Skips the start and end, which are often fully black.
+    (1..=SAMPLES)
+        .filter_map(|i| {
+            let position = duration * (0.05 + 0.9 * i as f64 / (SAMPLES + 1) as f64);
+            let luma = generator.luma(gst::ClockTime::from_seconds_f64(position));
+            bars(&luma, width, height)
+        })
+        // A dark scene may look like a bar, but a bar is dark in every frame.
+        .reduce(|a, b| Crop {
+            left: a.left.min(b.left),
+            top: a.top.min(b.top),
+            right: a.right.min(b.right),
+            bottom: a.bottom.min(b.bottom),
+        })
+        .unwrap_or_default()
+}
+
+/// The black bars at the edges of a single frame, `None` when it is black
+/// all over.
+fn bars(luma: &[u8], width: usize, height: usize) -> Option<Crop> {
+    if width == 0 || height == 0 || luma.len() < width * height {
+        return None;
+    }
+
+    let row = |y: usize| is_black((0..width).map(|x| luma[y * width + x]), width);
+    let column = |x: usize| is_black((0..height).map(|y| luma[y * width + x]), height);
+
+    let top = (0..height).take_while(|&y| row(y)).count();
+    if top == height {
+        return None;
+    }
+
+    let bottom = (top..height).rev().take_while(|&y| row(y)).count();
+    let left = (0..width).take_while(|&x| column(x)).count();
+    let right = (left..width).rev().take_while(|&x| column(x)).count();
+
+    let edge = |bar: usize, size: usize| {
+        let bar = bar as f32 / size as f32;
+        if bar < MIN_BAR {
+            0.0
+        } else {
+            bar.min(Crop::MAX)
+        }
+    };
+
+    Some(Crop {
+        left: edge(left, width),
+        top: edge(top, height),
+        right: edge(right, width),
+        bottom: edge(bottom, height),
+    })
+}
+
+fn is_black(pixels: impl Iterator<Item = u8>, len: usize) -> bool {
+    let bright = pixels.filter(|&pixel| pixel > BLACK).count();
+    bright as f32 <= len as f32 * TOLERANCE
+}
@@ -0,0 +1,448 @@
+//! The play queue driving [`Player`](super::Player) navigation.
+use crate::t;
+use crate::utils::icons;
+use crate::video::{Video, VideoId};
+use iced::widget::Text;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Repeat {
+    #[default]
+    Off,
+    /// Replays the current video when it ends.
+    One,
+    /// Wraps around to the start of the queue after the last video.
+    All,
+}
+
+impl Repeat {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Off => Self::All,
+            Self::All => Self::One,
+            Self::One => Self::Off,
+        }
+    }
+
+    pub fn icon<'a>(&self) -> Text<'a> {
+        match self {
+            Self::Off => icons::icon(icons::UNLOOP),
+            Self::One => icons::lucide(icons::LOOP_ONE),
+            Self::All => icons::icon(icons::LOOP),
+        }
+    }
+}
+
+impl std::fmt::Display for Repeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => t!("repeat-off"),
+                Self::One => t!("repeat-one"),
+                Self::All => t!("repeat-all"),
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Queue {
+    videos: Vec<Video>,
+    /// The playback order as indices into `videos`.
+    order: Vec<usize>,
+    /// The position of the current video within `order`.
+    current: usize,
+    shuffle: bool,
+    /// The play order from before shuffling, restored afterwards.
+    unshuffled: Vec<usize>,
+    pub repeat: Repeat,
+}
+
+impl Queue {
+    /// Creates a queue from `videos` starting playback at `start`.
+    /// The first video is used if `start` is missing.
+    pub fn new(videos: impl IntoIterator<Item = Video>, start: VideoId) -> Self {
+        let videos = videos.into_iter().collect::<Vec<_>>();
+        let current = videos
+            .iter()
+            .position(|video| video.id == start)
+            .unwrap_or_default();
+
+        Self {
+            order: (0..videos.len()).collect(),
+            videos,
+            current,
+            shuffle: false,
+            unshuffled: vec![],
+            repeat: Repeat::default(),
+        }
+    }
+
+    /// Creates a queue of every episode in the same season as `start`,
+    /// ordered by episode number.
+    pub fn season(videos: impl IntoIterator<Item = Video>, start: &Video) -> Self {
+        let Some(series) = start.series.as_ref() else {
+            return Self::new([start.clone()], start.id);
+        };
+
+        let mut episodes = videos
+            .into_iter()
+            .filter(|video| {
+                video
+                    .series
+                    .as_ref()
+                    .is_some_and(|other| other.name == series.name && other.season == series.season)
+            })
+            .collect::<Vec<_>>();
+
+        episodes.sort_by_key(|video| video.series.as_ref().map(|series| series.episode));
+
+        Self::new(episodes, start.id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_shuffled(&self) -> bool {
+        self.shuffle
+    }
+
+    pub fn current(&self) -> Option<&Video> {
+        self.order
+            .get(self.current)
+            .and_then(|index| self.videos.get(*index))
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut Video> {
+        self.order
+            .get(self.current)
+            .and_then(|index| self.videos.get_mut(*index))
+    }
+
+    /// The position of the current video within the play order.
+    pub fn position(&self) -> usize {
+        self.current
+    }
+
+    /// Iterates over the videos in play order.
+    pub fn iter(&self) -> impl Iterator<Item = &Video> {
+        self.order.iter().map(|index| &self.videos[*index])
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.current + 1 < self.order.len()
+            || (matches!(self.repeat, Repeat::All) && !self.is_empty())
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.current > 0 || (matches!(self.repeat, Repeat::All) && !self.is_empty())
+    }
+
+    /// Returns the video which would play after the current one.
+    pub fn peek_next(&self) -> Option<&Video> {
+        if self.current + 1 < self.order.len() {
+            self.videos.get(self.order[self.current + 1])
+        } else if matches!(self.repeat, Repeat::All) {
+            self.order.first().map(|index| &self.videos[*index])
+        } else {
+            None
+        }
+    }
+
+    /// Moves to the next video, wrapping around only when repeating all.
+    pub fn next(&mut self) -> Option<&Video> {
+        if !self.has_next() {
+            return None;
+        }
+
+        self.current = (self.current + 1) % self.order.len();
+        self.current()
+    }
+
+    /// Moves to the previous video, wrapping around only when repeating all.
+    pub fn previous(&mut self) -> Option<&Video> {
+        if !self.has_previous() {
+            return None;
+        }
+
+        self.current = self.current.checked_sub(1).unwrap_or(self.order.len() - 1);
+        self.current()
+    }
+
+    /// Returns the video to play once the current one has finished, if any,
+    /// without moving to it.
+    pub fn on_end(&self) -> Option<&Video> {
+        match self.repeat {
+            Repeat::One => self.current(),
+            _ => self.peek_next(),
+        }
+    }
+
+    /// Moves to the video at `position` in the play order.
+    pub fn jump(&mut self, position: usize) -> Option<&Video> {
+        if position >= self.order.len() {
+            return None;
+        }
+
+        self.current = position;
+        self.current()
+    }
+
+    /// Appends a video to the end of the queue.
+    pub fn add(&mut self, video: Video) {
+        self.videos.push(video);
+        self.order.push(self.videos.len() - 1);
+    }
+
+    /// Inserts a video right after the current one.
+    pub fn play_next(&mut self, video: Video) {
+        self.videos.push(video);
+        let at = if self.is_empty() { 0 } else { self.current + 1 };
+        self.order.insert(at, self.videos.len() - 1);
+    }
+
+    pub fn move_up(&mut self, position: usize) {
+        if position == 0 || position >= self.order.len() {
+            return;
+        }
+
+        self.swap(position, position - 1);
+    }
+
+    pub fn move_down(&mut self, position: usize) {
+        if position + 1 >= self.order.len() {
+            return;
+        }
+
+        self.swap(position, position + 1);
+    }
+
+    /// Removes the video at `position` from the play order.
+    /// The current video cannot be removed.
+    pub fn remove(&mut self, position: usize) {
+        if position == self.current || position >= self.order.len() {
+            return;
+        }
+
+        self.order.remove(position);
+
+        if position < self.current {
+            self.current -= 1;
+        }
+    }
+
+    pub fn toggle_shuffle(&mut self) {
+        self.shuffle = !self.shuffle;
+        let Some(&current) = self.order.get(self.current) else {
+            return;
+        };
+
+        if self.shuffle {
+            let mut rest = self
+                .order
+                .iter()
+                .copied()
+                .filter(|index| *index != current)
+                .collect::<Vec<_>>();
+            shuffle(&mut rest);
+
+            self.unshuffled = std::mem::replace(
+                &mut self.order,
+                std::iter::once(current).chain(rest).collect(),
+            );
+            self.current = 0;
+        } else {
+            // Videos removed while shuffled stay removed, and those added
+            // go last.
+            let unshuffled = std::mem::take(&mut self.unshuffled);
+            let added = self
+                .order
+                .iter()
+                .copied()
+                .filter(|index| !unshuffled.contains(index))
+                .collect::<Vec<_>>();
+
+            self.order = unshuffled
+                .into_iter()
+                .filter(|index| self.order.contains(index))
+                .chain(added)
+                .collect();
+            self.current = self
+                .order
+                .iter()
+                .position(|index| *index == current)
+                .unwrap_or_default();
+        }
+    }
+
+    fn swap(&mut self, x: usize, y: usize) {
+        self.order.swap(x, y);
+
+        if self.current == x {
+            self.current = y;
+        } else if self.current == y {
+            self.current = x;
+        }
+    }
+}
+
+/// Fisher-Yates shuffle seeded from the system clock.
+fn shuffle(items: &mut [usize]) {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let mut seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_nanos() as u64)
+        .unwrap_or(0x2545_F491_4F6C_DD1D)
+        | 1;
+
+    for i in (1..items.len()).rev() {
+        // xorshift64
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+
+        let j = (seed % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(id: usize) -> Video {
+        Video::from_path(id, format!("{id}.mkv"))
+    }
+
+    /// A queue of `count` videos named after their position, starting at `start`.
+    fn queue(count: usize, start: usize) -> Queue {
+        Queue::new((0..count).map(video), video(start).id)
+    }
+
+    fn names(queue: &Queue) -> Vec<String> {
+        queue.iter().map(|video| video.name.clone()).collect()
+    }
+
+    fn name(video: Option<&Video>) -> Option<&str> {
+        video.map(|video| video.name.as_str())
+    }
+
+    #[test]
+    fn starts_at_the_given_video() {
+        assert_eq!(name(queue(3, 1).current()), Some("1"));
+        assert_eq!(
+            name(Queue::new((0..3).map(video), video(9).id).current()),
+            Some("0")
+        );
+    }
+
+    #[test]
+    fn peek_next_wraps_only_when_repeating_all() {
+        let mut queue = queue(3, 2);
+        assert_eq!(name(queue.peek_next()), None);
+
+        queue.repeat = Repeat::One;
+        assert_eq!(name(queue.peek_next()), None);
+
+        queue.repeat = Repeat::All;
+        assert_eq!(name(queue.peek_next()), Some("0"));
+        assert_eq!(name(queue.next()), Some("0"));
+        assert_eq!(name(queue.previous()), Some("2"));
+    }
+
+    #[test]
+    fn on_end_follows_repeat_without_moving() {
+        let mut queue = queue(2, 1);
+        assert_eq!(name(queue.on_end()), None);
+
+        queue.repeat = Repeat::One;
+        assert_eq!(name(queue.on_end()), Some("1"));
+
+        queue.repeat = Repeat::All;
+        assert_eq!(name(queue.on_end()), Some("0"));
+        assert_eq!(queue.position(), 1);
+    }
+
+    #[test]
+    fn repeat_cycles_through_every_mode() {
+        let mut repeat = Repeat::default();
+        let mut seen = vec![];
+        for _ in 0..3 {
+            repeat.cycle();
+            seen.push(repeat);
+        }
+
+        assert_eq!(seen, [Repeat::All, Repeat::One, Repeat::Off]);
+    }
+
+    #[test]
+    fn shuffle_keeps_the_current_video_first() {
+        let mut queue = queue(10, 4);
+        queue.toggle_shuffle();
+
+        assert!(queue.is_shuffled());
+        assert_eq!(queue.position(), 0);
+        assert_eq!(name(queue.current()), Some("4"));
+
+        let mut shuffled = names(&queue);
+        shuffled.sort_by_key(|name| name.parse::<usize>().unwrap());
+        assert_eq!(shuffled, names(&self::queue(10, 0)));
+    }
+
+    #[test]
+    fn unshuffle_restores_the_order() {
+        let mut queue = queue(10, 4);
+        queue.toggle_shuffle();
+        queue.next();
+        let current = queue.current().cloned().unwrap();
+        queue.toggle_shuffle();
+
+        assert!(!queue.is_shuffled());
+        assert_eq!(names(&queue), names(&self::queue(10, 0)));
+        assert_eq!(name(queue.current()), Some(current.name.as_str()));
+    }
+
+    #[test]
+    fn unshuffle_keeps_changes_made_while_shuffled() {
+        let mut queue = queue(4, 0);
+        queue.toggle_shuffle();
+
+        let removed = queue.iter().position(|video| video.name == "2").unwrap();
+        queue.remove(removed);
+        queue.add(video(4));
+        queue.toggle_shuffle();
+
+        assert_eq!(names(&queue), ["0", "1", "3", "4"]);
+        assert_eq!(name(queue.current()), Some("0"));
+    }
+
+    #[test]
+    fn play_next_goes_after_the_current_video() {
+        let mut queue = queue(3, 1);
+        queue.play_next(video(3));
+
+        assert_eq!(names(&queue), ["0", "1", "3", "2"]);
+        assert_eq!(name(queue.peek_next()), Some("3"));
+    }
+
+    #[test]
+    fn moving_and_removing_follow_the_current_video() {
+        let mut queue = queue(4, 1);
+        queue.move_up(1);
+        assert_eq!(queue.position(), 0);
+
+        queue.remove(0);
+        assert_eq!(queue.len(), 4);
+
+        queue.remove(2);
+        assert_eq!(names(&queue), ["1", "0", "3"]);
+        assert_eq!(name(queue.current()), Some("1"));
+    }
+}
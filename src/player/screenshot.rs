@@ -0,0 +1,76 @@
+//! Saving the current frame of the video as a picture.
+use gstreamer::{self as gst, prelude::*};
+use gstreamer_app as gst_app;
+use std::path::Path;
+
+use crate::error::*;
+
+/// The last frame shown by the playbin `pipeline`, at its native resolution.
+pub fn frame(pipeline: &gst::Pipeline) -> Option<gst::Sample> {
+    pipeline.property::<Option<gst::Sample>>("sample")
+}
+
+/// Encodes `frame` as a PNG at `path`, drawing `subtitles` over it when given.
+pub fn save(frame: &gst::Sample, subtitles: Option<&str>, path: &Path) -> Result<()> {
+    gst::init().map_err(GStreamerError::Glib)?;
+
+    let overlay = if subtitles.is_some() {
+        "textoverlay name=overlay valignment=bottom halignment=center ! videoconvert !"
+    } else {
+        ""
+    };
+    let template = format!(
+        "appsrc name=src format=time ! videoconvert ! {overlay} pngenc snapshot=true ! appsink name=sink sync=false"
+    );
+
+    let pipeline = gst::parse::launch(&template)
+        .map_err(GStreamerError::Glib)?
+        .downcast::<gst::Pipeline>()
+        .unwrap();
+
+    let src = pipeline
+        .by_name("src")
+        .and_then(|src| src.downcast::<gst_app::AppSrc>().ok())
+        .unwrap();
+    let sink = pipeline
+        .by_name("sink")
+        .and_then(|sink| sink.downcast::<gst_app::AppSink>().ok())
+        .unwrap();
+
+    if let (Some(text), Some(overlay)) = (subtitles, pipeline.by_name("overlay")) {
+        overlay.set_property("text", text);
+    }
+
+    src.set_caps(frame.caps());
+
+    let result = (|| -> Result<()> {
+        pipeline
+            .set_state(gst::State::Playing)
+            .map_err(GStreamerError::StateChangeError)?;
+
+        src.push_sample(frame)
+            .map_err(|_| GStreamerError::BoolError(glib::bool_error!("Could not push frame")))?;
+        let _ = src.end_of_stream();
+
+        let sample = sink.pull_sample().map_err(GStreamerError::BoolError)?;
+        let buffer = sample
+            .buffer()
+            .ok_or_else(|| GStreamerError::BoolError(glib::bool_error!("Empty PNG sample")))?;
+        let map = buffer
+            .map_readable()
+            .map_err(|_| GStreamerError::BoolError(glib::bool_error!("Unreadable PNG")))?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, map.as_slice())?;
+
+        Ok(())
+    })();
+
+    if let Err(err) = pipeline.set_state(gst::State::Null) {
+        eprintln!("{err}");
+    }
+
+    result
+}
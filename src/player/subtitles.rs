@@ -0,0 +1,403 @@
+//! External subtitle files placed next to a video.
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Srt,
+    WebVtt,
+    Ass,
+}
+
+impl Format {
+    pub const EXTENSIONS: [&str; 4] = ["srt", "vtt", "ass", "ssa"];
+
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "srt" => Some(Self::Srt),
+            "vtt" => Some(Self::WebVtt),
+            "ass" | "ssa" => Some(Self::Ass),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: Duration,
+    pub end: Duration,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subtitles {
+    pub path: PathBuf,
+    /// Cues sorted by their start time.
+    cues: Vec<Cue>,
+}
+
+impl Subtitles {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let format =
+            Format::from_path(path).ok_or_else(|| Error::UnsupportedSubtitle(path.to_owned()))?;
+        let content = std::fs::read_to_string(path)?;
+
+        Ok(Self::parse(path, &content, format))
+    }
+
+    pub fn parse(path: impl Into<PathBuf>, content: &str, format: Format) -> Self {
+        // Byte order marks trip up the timestamp parsing of the first cue.
+        let content = content.trim_start_matches('\u{feff}');
+
+        let mut cues = match format {
+            Format::Srt | Format::WebVtt => parse_blocks(content),
+            Format::Ass => parse_ass(content),
+        };
+        cues.sort_by_key(|cue| cue.start);
+
+        Self {
+            path: path.into(),
+            cues,
+        }
+    }
+
+    /// A short label for the file, relative to its video.
+    pub fn name(&self) -> &str {
+        self.path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+    }
+
+    /// Whether the file name is tagged with `language`, as in `movie.en.srt`.
+    pub fn speaks(&self, language: &str) -> bool {
+        self.tags()
+            .any(|tag| super::tracks::same_language(tag, language))
+    }
+
+    /// Whether the file name is tagged as forced, as in `movie.en.forced.srt`.
+    pub fn is_forced(&self) -> bool {
+        self.tags().any(|tag| tag.eq_ignore_ascii_case("forced"))
+    }
+
+    fn tags(&self) -> impl Iterator<Item = &str> {
+        self.path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .split('.')
+            .skip(1)
+    }
+
+    /// Returns the text of every cue showing at `position`.
+    pub fn at(&self, position: Duration) -> Option<String> {
+        let started = self.cues.partition_point(|cue| cue.start <= position);
+
+        let text = self.cues[..started]
+            .iter()
+            .filter(|cue| cue.end > position)
+            .map(|cue| cue.text.as_str())
+            .collect::<Vec<_>>();
+
+        (!text.is_empty()).then(|| text.join("\n"))
+    }
+}
+
+/// Returns the subtitle files sharing a name with `video`, including
+/// language tagged ones such as `movie.en.srt`.
+pub fn sidecars(video: impl AsRef<Path>) -> Vec<PathBuf> {
+    let video = video.as_ref();
+    let (Some(dir), Some(stem)) = (
+        video.parent(),
+        video.file_stem().and_then(|stem| stem.to_str()),
+    ) else {
+        return vec![];
+    };
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut found = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| Format::from_path(path).is_some())
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name == stem
+                        || name
+                            .strip_prefix(stem)
+                            .is_some_and(|rest| rest.starts_with('.'))
+                })
+        })
+        .collect::<Vec<_>>();
+
+    found.sort();
+    found
+}
+
+/// Parses SubRip and WebVTT files, which both separate cues with blank lines.
+fn parse_blocks(content: &str) -> Vec<Cue> {
+    let content = content.replace("\r\n", "\n");
+
+    content
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+            let (start, end) = lines.next()?.split_once("-->")?;
+
+            let start = parse_timestamp(start.trim())?;
+            // WebVTT allows cue settings after the end timestamp.
+            let end = parse_timestamp(end.split_whitespace().next()?)?;
+
+            let text = lines.map(strip_tags).collect::<Vec<_>>().join("\n");
+
+            Some(Cue { start, end, text })
+        })
+        .collect()
+}
+
+/// Parses `hh:mm:ss,mmm`, `hh:mm:ss.mmm` and `mm:ss.mmm` timestamps.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let timestamp = timestamp.replace(',', ".");
+    let (clock, fraction) = timestamp
+        .split_once('.')
+        .unwrap_or((timestamp.as_str(), "0"));
+
+    let mut secs = 0u64;
+    for part in clock.split(':') {
+        secs = secs * 60 + part.trim().parse::<u64>().ok()?;
+    }
+
+    let digits = fraction.len().min(9) as u32;
+    let fraction = fraction.get(..digits as usize)?.parse::<u64>().ok()?;
+    let nanos = fraction * 10u64.pow(9 - digits);
+
+    Some(Duration::from_secs(secs) + Duration::from_nanos(nanos))
+}
+
+/// Removes html style tags such as `<i>` and ssa overrides such as `{\an8}`.
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut closing = None;
+
+    for char in line.chars() {
+        match (closing, char) {
+            (None, '<') => closing = Some('>'),
+            (None, '{') => closing = Some('}'),
+            (Some(close), char) if char == close => closing = None,
+            (Some(_), _) => {}
+            (None, char) => text.push(char),
+        }
+    }
+
+    text
+}
+
+/// Parses the `[Events]` section of Advanced SubStation Alpha files.
+fn parse_ass(content: &str) -> Vec<Cue> {
+    let mut in_events = false;
+    let mut format = vec![];
+    let mut cues = vec![];
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_events = line.eq_ignore_ascii_case("[events]");
+            continue;
+        }
+
+        if !in_events {
+            continue;
+        }
+
+        if let Some(fields) = line.strip_prefix("Format:") {
+            format = fields
+                .split(',')
+                .map(|field| field.trim().to_lowercase())
+                .collect();
+        } else if let Some(fields) = line.strip_prefix("Dialogue:") {
+            let field = |name: &str| format.iter().position(|field| field == name);
+            let (Some(start), Some(end), Some(text)) =
+                (field("start"), field("end"), field("text"))
+            else {
+                continue;
+            };
+
+            // The text is always the last field and may itself contain commas.
+            let fields = fields.splitn(format.len(), ',').collect::<Vec<_>>();
+
+            let (Some(start), Some(end), Some(text)) = (
+                fields
+                    .get(start)
+                    .and_then(|time| parse_timestamp(time.trim())),
+                fields
+                    .get(end)
+                    .and_then(|time| parse_timestamp(time.trim())),
+                fields.get(text),
+            ) else {
+                continue;
+            };
+
+            let text = strip_tags(text)
+                .replace("\\N", "\n")
+                .replace("\\n", "\n")
+                .replace("\\h", " ");
+
+            cues.push(Cue { start, end, text });
+        }
+    }
+
+    cues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srt(content: &str) -> Subtitles {
+        Subtitles::parse("movie.srt", content, Format::Srt)
+    }
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn reads_both_timestamp_styles() {
+        assert_eq!(parse_timestamp("01:02:03,456"), Some(ms(3_723_456)));
+        assert_eq!(parse_timestamp("01:02:03.456"), Some(ms(3_723_456)));
+        assert_eq!(parse_timestamp("02:03.5"), Some(ms(123_500)));
+        assert_eq!(parse_timestamp("0:00:01.25"), Some(ms(1250)));
+        assert_eq!(parse_timestamp("00:05"), Some(ms(5000)));
+    }
+
+    #[test]
+    fn rejects_malformed_timestamps() {
+        for timestamp in ["", "aa:bb:cc", "00:01,x", "00::01.000", "-1:00.000"] {
+            assert_eq!(parse_timestamp(timestamp), None, "{timestamp:?} was read");
+        }
+    }
+
+    #[test]
+    fn parses_srt_cues() {
+        let subtitles = srt("1\n00:00:01,000 --> 00:00:02,500\n<i>Hello</i>\nthere\n\n\
+             2\n00:00:03,000 --> 00:00:04,000\n{\\an8}Bye\n");
+
+        assert_eq!(subtitles.at(ms(500)), None);
+        assert_eq!(subtitles.at(ms(1000)).as_deref(), Some("Hello\nthere"));
+        assert_eq!(subtitles.at(ms(2500)), None);
+        assert_eq!(subtitles.at(ms(3500)).as_deref(), Some("Bye"));
+    }
+
+    #[test]
+    fn parses_crlf_and_byte_order_marks() {
+        let subtitles = srt(
+            "\u{feff}1\r\n00:00:01,000 --> 00:00:02,000\r\nHello\r\nthere\r\n\r\n\
+             2\r\n00:00:03,000 --> 00:00:04,000\r\nBye\r\n",
+        );
+
+        assert_eq!(subtitles.at(ms(1500)).as_deref(), Some("Hello\nthere"));
+        assert_eq!(subtitles.at(ms(3500)).as_deref(), Some("Bye"));
+    }
+
+    #[test]
+    fn skips_malformed_cues() {
+        let subtitles = srt("1\n00:00:01,000 -> 00:00:02,000\nNo arrow\n\n\
+             2\nbroken --> 00:00:04,000\nBad start\n\n\
+             3\n00:00:05,000 --> \nNo end\n\n\
+             4\n00:00:06,000 --> 00:00:07,000\nKept\n");
+
+        assert_eq!(subtitles.cues.len(), 1);
+        assert_eq!(subtitles.at(ms(6000)).as_deref(), Some("Kept"));
+    }
+
+    #[test]
+    fn parses_webvtt_with_cue_settings() {
+        let subtitles = Subtitles::parse(
+            "movie.vtt",
+            "WEBVTT\n\n00:01.000 --> 00:02.000 align:start position:10%\nHello\n\n\
+             intro\n01:00:00.000 --> 01:00:01.000\nLater\n",
+            Format::WebVtt,
+        );
+
+        assert_eq!(subtitles.at(ms(1500)).as_deref(), Some("Hello"));
+        assert_eq!(subtitles.at(ms(3_600_500)).as_deref(), Some("Later"));
+    }
+
+    #[test]
+    fn joins_overlapping_cues_in_start_order() {
+        let subtitles = srt("2\n00:00:02,000 --> 00:00:05,000\nSecond\n\n\
+             1\n00:00:01,000 --> 00:00:05,000\nFirst\n");
+
+        assert_eq!(subtitles.at(ms(3000)).as_deref(), Some("First\nSecond"));
+    }
+
+    #[test]
+    fn parses_ass_events() {
+        let subtitles = Subtitles::parse(
+            "movie.ass",
+            "[Script Info]\r\nTitle: Test\r\n\r\n[Events]\r\n\
+             Format: Layer, Start, End, Style, Text\r\n\
+             Dialogue: 0,0:00:01.50,0:00:03.00,Default,{\\i1}Hello,\\Nthere\r\n\
+             Dialogue: 0,broken,0:00:05.00,Default,Skipped\r\n\
+             Comment: 0,0:00:01.00,0:00:09.00,Default,Hidden\r\n",
+            Format::Ass,
+        );
+
+        assert_eq!(subtitles.cues.len(), 1);
+        assert_eq!(subtitles.at(ms(2000)).as_deref(), Some("Hello,\nthere"));
+    }
+
+    #[test]
+    fn reads_language_and_forced_tags() {
+        let tagged = Subtitles::parse("dir/movie.en.forced.srt", "", Format::Srt);
+        assert!(tagged.speaks("en"));
+        assert!(tagged.speaks("English"));
+        assert!(!tagged.speaks("fr"));
+        assert!(tagged.is_forced());
+
+        let plain = Subtitles::parse("dir/movie.srt", "", Format::Srt);
+        assert!(!plain.speaks("en"));
+        assert!(!plain.is_forced());
+    }
+
+    #[test]
+    fn recognises_subtitle_extensions() {
+        assert_eq!(Format::from_path("a.SRT"), Some(Format::Srt));
+        assert_eq!(Format::from_path("a.vtt"), Some(Format::WebVtt));
+        assert_eq!(Format::from_path("a.ssa"), Some(Format::Ass));
+        assert_eq!(Format::from_path("a.txt"), None);
+    }
+
+    #[test]
+    fn finds_sidecars_sharing_the_video_name() {
+        let dir = std::env::temp_dir().join(format!("kino-sidecars-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "movie.mkv",
+            "movie.srt",
+            "movie.en.forced.srt",
+            "movie.fr.ass",
+            "movie.nfo",
+            "movie2.srt",
+            "other.srt",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let found = sidecars(dir.join("movie.mkv"));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let names = found
+            .iter()
+            .filter_map(|path| path.file_name()?.to_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["movie.en.forced.srt", "movie.fr.ass", "movie.srt"]);
+    }
+}
@@ -0,0 +1,223 @@
+//! Audio and subtitle streams embedded in the playing container.
+use glib::object::ObjectExt;
+use gstreamer as gst;
+use iced::futures::{Stream, channel::mpsc};
+
+use crate::t;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackKind {
+    Audio,
+    Text,
+}
+
+impl TrackKind {
+    fn count_property(&self) -> &'static str {
+        match self {
+            Self::Audio => "n-audio",
+            Self::Text => "n-text",
+        }
+    }
+
+    fn current_property(&self) -> &'static str {
+        match self {
+            Self::Audio => "current-audio",
+            Self::Text => "current-text",
+        }
+    }
+
+    fn tags_signal(&self) -> &'static str {
+        match self {
+            Self::Audio => "get-audio-tags",
+            Self::Text => "get-text-tags",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    pub index: i32,
+    pub kind: TrackKind,
+    /// An ISO 639 language code such as `en` or `jpn`.
+    pub language: Option<String>,
+    pub language_name: Option<String>,
+    pub codec: Option<String>,
+    pub title: Option<String>,
+}
+
+impl Track {
+    /// A label such as `English (Commentary) · AAC`.
+    pub fn label(&self) -> String {
+        let mut label = self
+            .language_name
+            .clone()
+            .or_else(|| self.language.clone())
+            .unwrap_or_else(|| t!("player-track", number = self.index + 1));
+
+        if let Some(title) = self.title.as_ref() {
+            label.push_str(&format!(" ({title})"));
+        }
+
+        if let Some(codec) = self.codec.as_ref() {
+            label.push_str(&format!(" · {codec}"));
+        }
+
+        label
+    }
+
+    /// Whether the track is in `language`, given as a code or an English name.
+    pub fn speaks(&self, language: &str) -> bool {
+        self.language
+            .iter()
+            .chain(self.language_name.iter())
+            .any(|own| same_language(own, language))
+    }
+
+    /// Whether the track only carries forced subtitles, such as signs and
+    /// foreign dialogue.
+    pub fn is_forced(&self) -> bool {
+        self.title
+            .as_ref()
+            .is_some_and(|title| title.to_lowercase().contains("forced"))
+    }
+}
+
+/// Two and three letter codes along with the English name of common languages.
+const LANGUAGES: [&[&str]; 20] = [
+    &["en", "eng", "english"],
+    &["ja", "jpn", "japanese"],
+    &["fr", "fre", "fra", "french"],
+    &["de", "ger", "deu", "german"],
+    &["es", "spa", "spanish"],
+    &["it", "ita", "italian"],
+    &["pt", "por", "portuguese"],
+    &["ru", "rus", "russian"],
+    &["zh", "chi", "zho", "chinese"],
+    &["ko", "kor", "korean"],
+    &["ar", "ara", "arabic"],
+    &["hi", "hin", "hindi"],
+    &["nl", "dut", "nld", "dutch"],
+    &["sv", "swe", "swedish"],
+    &["no", "nor", "norwegian"],
+    &["da", "dan", "danish"],
+    &["fi", "fin", "finnish"],
+    &["pl", "pol", "polish"],
+    &["tr", "tur", "turkish"],
+    &["el", "gre", "ell", "greek"],
+];
+
+/// Compares two languages, each given as an ISO 639 code or an English name.
+pub fn same_language(left: &str, right: &str) -> bool {
+    let left = left.trim().to_lowercase();
+    let right = right.trim().to_lowercase();
+
+    if left == right {
+        return true;
+    }
+
+    LANGUAGES
+        .iter()
+        .any(|names| names.contains(&left.as_str()) && names.contains(&right.as_str()))
+}
+
+/// Lists the streams of `kind` known to the playbin `pipeline`.
+pub fn tracks(pipeline: &gst::Pipeline, kind: TrackKind) -> Vec<Track> {
+    let count = pipeline.property::<i32>(kind.count_property());
+
+    (0..count)
+        .map(|index| {
+            let tags = pipeline.emit_by_name::<Option<gst::TagList>>(kind.tags_signal(), &[&index]);
+
+            let (language, language_name, codec, title) = match tags {
+                Some(tags) => {
+                    let language = tags
+                        .get::<gst::tags::LanguageCode>()
+                        .map(|code| code.get().to_owned());
+                    let language_name = tags
+                        .get::<gst::tags::LanguageName>()
+                        .map(|name| name.get().to_owned());
+                    let codec = match kind {
+                        TrackKind::Audio => tags
+                            .get::<gst::tags::AudioCodec>()
+                            .map(|codec| codec.get().to_owned()),
+                        TrackKind::Text => tags
+                            .get::<gst::tags::SubtitleCodec>()
+                            .map(|codec| codec.get().to_owned()),
+                    };
+                    let title = tags
+                        .get::<gst::tags::Title>()
+                        .map(|title| title.get().to_owned());
+
+                    (language, language_name, codec, title)
+                }
+                None => (None, None, None, None),
+            };
+
+            Track {
+                index,
+                kind,
+                language,
+                language_name,
+                codec,
+                title,
+            }
+        })
+        .collect()
+}
+
+pub fn current(pipeline: &gst::Pipeline, kind: TrackKind) -> i32 {
+    pipeline.property::<i32>(kind.current_property())
+}
+
+/// Switches to the stream at `index`. Playback carries on from the same position.
+pub fn select(pipeline: &gst::Pipeline, kind: TrackKind, index: i32) {
+    if matches!(kind, TrackKind::Text) {
+        set_text_enabled(pipeline, true);
+    }
+
+    pipeline.set_property(kind.current_property(), index);
+}
+
+/// Turns rendering of embedded subtitles on or off.
+pub fn set_text_enabled(pipeline: &gst::Pipeline, enabled: bool) {
+    let flags = pipeline.property_value("flags");
+    let Some(class) = glib::FlagsClass::with_type(flags.type_()) else {
+        return;
+    };
+
+    let Some(builder) = class.builder_with_value(flags) else {
+        return;
+    };
+
+    let builder = if enabled {
+        builder.set_by_nick("text")
+    } else {
+        builder.unset_by_nick("text")
+    };
+
+    if let Some(flags) = builder.build() {
+        pipeline.set_property_from_value("flags", &flags);
+    }
+}
+
+/// Delays embedded subtitles by `delay` milliseconds, or shows them early
+/// when negative.
+pub fn set_text_offset(pipeline: &gst::Pipeline, delay: i64) {
+    pipeline.set_property("text-offset", delay * 1_000_000);
+}
+
+/// Reports each time the playbin `pipeline` finds another audio or subtitle
+/// stream, until the pipeline is dropped.
+pub fn changes(pipeline: &gst::Pipeline) -> impl Stream<Item = ()> + use<> {
+    let (sender, receiver) = mpsc::unbounded();
+
+    for signal in ["audio-changed", "text-changed"] {
+        let sender = sender.clone();
+        pipeline.connect(signal, false, move |_| {
+            let _ = sender.unbounded_send(());
+            None
+        });
+    }
+
+    receiver
+}
@@ -0,0 +1,155 @@
+//! Colour themes and the styles shared across screens.
+//!
+//! Besides the built-in light and dark themes, a palette can be placed in
+//! the `themes` folder beside the settings as `<name>.toml`:
+//!
+//! ```toml
+//! background = "#1e1e2e"
+//! text = "#cdd6f4"
+//! accent = "#cba6f7"
+//! success = "#a6e3a1"
+//! danger = "#f38ba8"
+//! ```
+//!
+//! Colours left out are taken from the built-in theme closest to the
+//! background.
+use iced::{
+    Color, Shadow, Theme,
+    theme::Palette,
+    widget::{button, container, slider},
+};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::config::{self, ThemeChoice};
+use crate::error::*;
+
+/// A palette file in the themes folder.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PaletteFile {
+    background: Option<String>,
+    text: Option<String>,
+    accent: Option<String>,
+    success: Option<String>,
+    danger: Option<String>,
+}
+
+/// The theme for `choice`, falling back to the system's when a custom
+/// palette can't be read.
+pub fn resolve(choice: &ThemeChoice) -> Theme {
+    match choice {
+        ThemeChoice::System => Theme::default(),
+        ThemeChoice::Light => Theme::Light,
+        ThemeChoice::Dark => Theme::Dark,
+        ThemeChoice::Custom(name) => match load(name) {
+            Ok(theme) => theme,
+            Err(err) => {
+                eprintln!("Error loading theme {name}: \n{err}");
+                Theme::default()
+            }
+        },
+    }
+}
+
+/// The names of the palettes in the themes folder.
+pub fn custom() -> Vec<String> {
+    let Ok(entries) = folder().and_then(|folder| Ok(std::fs::read_dir(folder)?)) else {
+        return vec![];
+    };
+
+    let mut names = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+        .collect::<Vec<_>>();
+
+    names.sort();
+    names
+}
+
+pub fn folder() -> Result<PathBuf> {
+    config::config_file("themes")
+}
+
+fn load(name: &str) -> Result<Theme> {
+    let path = folder()?.join(format!("{name}.toml"));
+    let file: PaletteFile = toml::from_str(&std::fs::read_to_string(path)?)?;
+
+    let parse = |color: &Option<String>| color.as_deref().and_then(Color::parse);
+
+    let background = parse(&file.background);
+    let is_light = background.is_some_and(|color| color.r + color.g + color.b > 1.5);
+    let mut palette = if is_light {
+        Palette::LIGHT
+    } else {
+        Palette::DARK
+    };
+
+    palette.background = background.unwrap_or(palette.background);
+    palette.text = parse(&file.text).unwrap_or(palette.text);
+    palette.primary = parse(&file.accent).unwrap_or(palette.primary);
+    palette.success = parse(&file.success).unwrap_or(palette.success);
+    palette.danger = parse(&file.danger).unwrap_or(palette.danger);
+
+    Ok(Theme::custom(name.to_owned(), palette))
+}
+
+/// The accent colour of `theme`.
+pub fn accent(theme: &Theme) -> Color {
+    theme.palette().primary
+}
+
+/// A block of content set apart from the background, such as a card.
+pub fn card(theme: &Theme) -> container::Style {
+    let pair = theme.extended_palette().background.weak;
+
+    container::Style {
+        background: Some(pair.color.into()),
+        text_color: Some(pair.text),
+        ..container::Style::default()
+    }
+}
+
+/// A panel floating over other content, such as a menu.
+pub fn panel(theme: &Theme) -> container::Style {
+    let default = card(theme);
+    let background = default
+        .background
+        .map(|background| background.scale_alpha(0.9));
+
+    container::Style {
+        background,
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.75),
+            blur_radius: 20.0,
+            ..default.shadow
+        },
+        ..default
+    }
+}
+
+/// Marks `style` as the current choice among its neighbours.
+pub fn selected(theme: &Theme, style: button::Style) -> button::Style {
+    let background = theme.extended_palette().background.weakest;
+
+    button::Style {
+        background: Some(background.color.into()),
+        text_color: accent(theme),
+        ..style
+    }
+}
+
+/// The position bar of the player.
+pub fn seek_bar(theme: &Theme, status: slider::Status) -> slider::Style {
+    let mut style = slider::default(theme, status);
+    let accent = accent(theme);
+
+    style.rail.backgrounds.0 = accent.into();
+    style.handle.background = accent.into();
+    style
+}
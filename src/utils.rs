@@ -3,19 +3,25 @@ use gstreamer::{
     self as gst,
     prelude::{ElementExt, ElementExtManual, GstBinExt, GstBinExtManual, PadExt},
 };
+use gstreamer_video as gst_video;
 use iced::widget::image;
+use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use crate::error::*;
 use crate::home::HomeMessage;
+use crate::t;
 pub mod icons;
 pub use icons::*;
 pub mod typo;
 pub use typo::*;
 pub mod filter;
 pub use filter::*;
+pub mod library;
+pub mod loudness;
+pub mod palette;
 
 /// Returns an empty [`iced::Element`].
 pub fn empty<'a, Message: 'a>() -> iced::Element<'a, Message> {
@@ -258,7 +264,56 @@ impl ThumbnailGenerator {
         let width = self.width;
         let height = self.height;
         let downscale = self.downscale;
+        let frame = self.frame(position);
 
+        image::Handle::from_rgba(
+            width as u32 / downscale,
+            height as u32 / downscale,
+            yuv_to_rgba(frame.as_slice(), width as _, height as _, downscale),
+        )
+    }
+
+    /// Returns the brightness of every pixel of the frame at `position`, row
+    /// by row, at the full size of the video.
+    pub fn luma(&self, position: gst::ClockTime) -> Vec<u8> {
+        let sample = self.sample(position);
+        let info = sample
+            .caps()
+            .and_then(|caps| gst_video::VideoInfo::from_caps(caps).ok());
+        let (Some(buffer), Some(info)) = (sample.buffer(), info) else {
+            return vec![];
+        };
+        let Ok(frame) = gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &info) else {
+            return vec![];
+        };
+        // NV12 starts with the luma plane.
+        let Ok(plane) = frame.plane_data(0) else {
+            return vec![];
+        };
+
+        // Rows may be padded past the width of the picture.
+        let stride = frame.plane_stride()[0] as usize;
+        let width = frame.width() as usize;
+        plane
+            .chunks(stride)
+            .take(frame.height() as usize)
+            .flat_map(|row| &row[..width.min(row.len())])
+            .copied()
+            .collect()
+    }
+
+    fn frame(&self, position: gst::ClockTime) -> gst::MappedBuffer<gst::buffer::Readable> {
+        let buffer = self
+            .sample(position)
+            .buffer_owned()
+            .expect("Could get sample buffer");
+
+        buffer
+            .into_mapped_buffer_readable()
+            .expect("Couldn't map sample buffer")
+    }
+
+    fn sample(&self, position: gst::ClockTime) -> gst::Sample {
         self.pipeline
             .set_state(gst::State::Paused)
             .map_err(GStreamerError::StateChangeError)
@@ -275,23 +330,11 @@ impl ThumbnailGenerator {
             .map_err(GStreamerError::BoolError)
             .unwrap();
 
-        let sample = self
-            .sink
+        self.sink
             .pull_preroll()
             // .try_pull_preroll(gst::ClockTime::from_mseconds(250))
             // .expect("Couldn't pull sample");
-            .unwrap();
-        let buffer = sample.buffer().expect("Could get sample buffer");
-        let frame = buffer
-            .map_readable()
-            .map_err(GStreamerError::BoolError)
-            .unwrap();
-
-        image::Handle::from_rgba(
-            width as u32 / downscale,
-            height as u32 / downscale,
-            yuv_to_rgba(frame.as_slice(), width as _, height as _, downscale),
-        )
+            .unwrap()
     }
 }
 
@@ -334,7 +377,8 @@ pub fn rand_u32() -> u32 {
         .subsec_millis()
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ViewType {
     #[default]
     Grid,
@@ -414,7 +458,7 @@ impl SortKind {
         let order = order
             .map(|order| (order + 1).to_string())
             .unwrap_or_default();
-        let content = text(format!("{self} {}", order)).size(H7);
+        let content = text(format!("{self} {}", order)).size(h7());
         // let content = row!(content).spacing(2.0).align_y(Vertical::Center);
 
         button(content)
@@ -433,21 +477,42 @@ impl SortKind {
     }
 }
 
+impl std::str::FromStr for SortKind {
+    type Err = String;
+
+    /// Reads the name a sort is given on the command line, such as `rating`.
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "duration" => Ok(Self::Duration),
+            "progress" => Ok(Self::Progress),
+            "rating" => Ok(Self::Rating),
+            "release" => Ok(Self::Release),
+            "comments" => Ok(Self::Comments),
+            "added" => Ok(Self::Added),
+            "recent" => Ok(Self::Recent),
+            _ => Err(format!(
+                "Unknown sort \"{input}\", expected one of name, duration, progress, rating, release, comments, added or recent"
+            )),
+        }
+    }
+}
+
 impl std::fmt::Display for SortKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                Self::Name => "Name",
-                Self::Duration => "Duration",
-                Self::Progress => "Progress",
-                Self::Rating => "Rating",
-                // Self::Tags => "Tags",
-                Self::Release => "Release",
-                Self::Comments => "Comments",
-                Self::Added => "Date Added",
-                Self::Recent => "Recent",
+                Self::Name => t!("sort-name"),
+                Self::Duration => t!("sort-duration"),
+                Self::Progress => t!("sort-progress"),
+                Self::Rating => t!("sort-rating"),
+                // Self::Tags => t!("sort-tags"),
+                Self::Release => t!("sort-release"),
+                Self::Comments => t!("sort-comments"),
+                Self::Added => t!("sort-added"),
+                Self::Recent => t!("sort-recent"),
             }
         )
     }
@@ -1,5 +1,7 @@
 use std::fmt::{self, Display};
 
+use crate::t;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum FilterMode {
     #[default]
@@ -39,8 +41,8 @@ impl Display for FilterMode {
             f,
             "{}",
             match self {
-                Self::Or => "OR",
-                Self::And => "AND",
+                Self::Or => t!("filter-or"),
+                Self::And => t!("filter-and"),
             }
         )
     }
@@ -126,12 +128,12 @@ impl Display for ProgressKind {
             f,
             "{}",
             match self {
-                Self::Any => "Any",
-                Self::Zero => "0%",
-                Self::TwentyFive => "25%",
-                Self::Fifty => "50%",
-                Self::SeventyFive => "75%",
-                Self::Complete => "100%",
+                Self::Any => t!("filter-any"),
+                Self::Zero => "0%".to_string(),
+                Self::TwentyFive => "25%".to_string(),
+                Self::Fifty => "50%".to_string(),
+                Self::SeventyFive => "75%".to_string(),
+                Self::Complete => "100%".to_string(),
             }
         )
     }
@@ -190,7 +192,7 @@ impl Display for RatingKind {
             f,
             "{}",
             match self {
-                Self::Any => "Any".to_string(),
+                Self::Any => t!("filter-any"),
                 Self::One => 1.to_string(),
                 Self::Two => 2.to_string(),
                 Self::Three => 3.to_string(),
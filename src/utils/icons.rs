@@ -10,6 +10,8 @@ static LUCIDE_BYTES: &[u8] = include_bytes!("../../assets/lucide.ttf");
 static ICONS: &[u8] = include_bytes!("../../assets/kino-icons.ttf");
 pub const NAME: &str = "kino-icons";
 pub const FONT: Font = Font::with_name(NAME);
+/// Icons missing from the app's own font.
+pub const LUCIDE: &str = "lucide";
 
 pub const LOGO: char = '\u{e80b}';
 pub const SEARCH: char = '\u{e80c}';
@@ -19,6 +21,8 @@ pub const FORWARD: char = '\u{e80e}';
 
 pub const LOOP: char = '\u{e80f}';
 pub const UNLOOP: char = '\u{e80f}';
+/// From [`LUCIDE`].
+pub const LOOP_ONE: char = '\u{e1fd}';
 
 pub const CHEV_UP: char = '\u{e812}';
 pub const CHEV_DOWN: char = '\u{e811}';
@@ -87,7 +91,7 @@ pub const NUMBER: char = '\u{e838}';
 pub const EYE: char = '\u{e833}';
 
 pub fn load_fonts() -> iced::Task<Result<(), font::Error>> {
-    font::load(ICONS)
+    iced::Task::batch([font::load(ICONS), font::load(LUCIDE_BYTES)])
 }
 
 fn icon_maker<'a>(unicode: char, name: &'static str) -> Text<'a> {
@@ -96,13 +100,17 @@ fn icon_maker<'a>(unicode: char, name: &'static str) -> Text<'a> {
         .font(fnt)
         .align_x(alignment::Horizontal::Center)
         .line_height(1.0)
-        .size(P)
+        .size(p())
 }
 
 pub fn icon<'a>(unicode: char) -> Text<'a> {
     icon_maker(unicode, NAME)
 }
 
+pub fn lucide<'a>(unicode: char) -> Text<'a> {
+    icon_maker(unicode, LUCIDE)
+}
+
 /// Returns a text button
 pub fn text_button<'a, Message>(unicode: char) -> Button<'a, Message> {
     use iced::widget::{button, button::text};
@@ -0,0 +1,55 @@
+//! Finding the videos in the library folders.
+use std::path::{Path, PathBuf};
+
+use crate::config::Library;
+
+/// The file extensions taken to be videos.
+pub const EXTENSIONS: [&str; 8] = ["mkv", "mp4", "webm", "avi", "mov", "m4v", "wmv", "ts"];
+
+/// The videos in the folders of `library`, sorted by path.
+pub fn scan(library: &Library) -> Vec<PathBuf> {
+    let mut videos = vec![];
+
+    for folder in &library.folders {
+        walk(folder, library, &mut videos);
+    }
+
+    videos.sort();
+    videos.dedup();
+    videos
+}
+
+fn walk(dir: &Path, library: &Library, videos: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        eprintln!("Error reading library folder: {}", dir.display());
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden && !library.hidden {
+            continue;
+        }
+
+        // Links to folders are not followed, as they may loop.
+        let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+        if is_dir {
+            if library.recursive {
+                walk(&path, library, videos);
+            }
+        } else if is_video(&path) {
+            videos.push(path);
+        }
+    }
+}
+
+fn is_video(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            EXTENSIONS
+                .iter()
+                .any(|video| video.eq_ignore_ascii_case(extension))
+        })
+}
@@ -0,0 +1,79 @@
+//! EBU R128 loudness measurement of the audio of a file.
+use gstreamer::{self as gst, prelude::*};
+use std::path::Path;
+
+use crate::error::*;
+use crate::player::export::quote;
+use crate::video::Loudness;
+
+/// Decodes all of the audio of the file at `path` as fast as possible,
+/// measuring its integrated loudness and true peak. Video is left encoded.
+pub fn analyze(path: impl AsRef<Path>) -> Result<Loudness> {
+    gst::init().map_err(GStreamerError::Glib)?;
+
+    let description = format!(
+        "filesrc location={} ! decodebin caps=audio/x-raw ! audioconvert ! audioresample \
+         ! ebur128level post-messages=true ! fakesink sync=false",
+        quote(path.as_ref())
+    );
+    let pipeline = gst::parse::launch(&description)
+        .map_err(GStreamerError::Glib)?
+        .downcast::<gst::Pipeline>()
+        .unwrap();
+
+    let result = measure(&pipeline);
+
+    if let Err(err) = pipeline.set_state(gst::State::Null) {
+        eprintln!("{err}");
+    }
+
+    result
+}
+
+fn measure(pipeline: &gst::Pipeline) -> Result<Loudness> {
+    pipeline
+        .set_state(gst::State::Playing)
+        .map_err(GStreamerError::StateChangeError)?;
+
+    let bus = pipeline.bus().unwrap();
+    let mut loudness = None;
+
+    for message in bus.iter_timed(gst::ClockTime::NONE) {
+        match message.view() {
+            gst::MessageView::Eos(_) => break,
+            gst::MessageView::Error(err) => {
+                return Err(GStreamerError::Glib(err.error()).into());
+            }
+            // Each report covers all of the audio so far, so the last one
+            // holds the result.
+            gst::MessageView::Element(element) => {
+                if let Some(report) = element
+                    .structure()
+                    .filter(|structure| structure.name() == "ebur128-level")
+                {
+                    loudness = read(report).or(loudness);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    loudness.ok_or(Error::NoAudio)
+}
+
+fn read(report: &gst::StructureRef) -> Option<Loudness> {
+    let integrated = report.get::<f64>("global-loudness").ok()?;
+
+    // Linear, for each channel.
+    let true_peak = report
+        .get::<glib::ValueArray>("true-peak")
+        .ok()?
+        .iter()
+        .filter_map(|peak| peak.get::<f64>().ok())
+        .fold(0.0f64, f64::max);
+
+    integrated.is_finite().then(|| Loudness {
+        integrated,
+        true_peak: 20.0 * true_peak.max(f64::MIN_POSITIVE).log10(),
+    })
+}
@@ -0,0 +1,159 @@
+//! Picking the main colours of a poster or backdrop.
+use gstreamer::{self as gst, prelude::*};
+use gstreamer_video as gst_video;
+use std::path::Path;
+
+use crate::error::*;
+use crate::player::export::quote;
+use crate::video::Palette;
+
+/// The width and height the picture is shrunk to before it is looked at.
+const SIZE: usize = 48;
+/// The number of colours the picture is reduced to.
+const CLUSTERS: usize = 5;
+const ITERATIONS: usize = 12;
+/// The smallest share of the picture a colour needs to be picked as vibrant.
+const MIN_SHARE: f32 = 0.05;
+
+/// Decodes the picture at `path` and groups its pixels into a few colours
+/// by k-means.
+pub fn extract(path: impl AsRef<Path>) -> Result<Palette> {
+    let pixels = decode(path.as_ref())?;
+    let clusters = cluster(&pixels);
+
+    let total = pixels.len() as f32;
+    let dominant = clusters
+        .iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(color, _)| *color)
+        .ok_or(Error::NoPicture)?;
+    let vibrant = clusters
+        .iter()
+        .filter(|(_, count)| *count as f32 / total >= MIN_SHARE)
+        .map(|(color, _)| *color)
+        .max_by(|a, b| vibrance(*a).total_cmp(&vibrance(*b)))
+        .unwrap_or(dominant);
+
+    Ok(Palette {
+        dominant: rgb8(dominant),
+        vibrant: rgb8(vibrant),
+    })
+}
+
+/// The pixels of the picture at `path`, shrunk to [`SIZE`] by [`SIZE`].
+fn decode(path: &Path) -> Result<Vec<[f32; 3]>> {
+    gst::init().map_err(GStreamerError::Glib)?;
+
+    let description = format!(
+        "filesrc location={} ! decodebin ! videoconvert ! videoscale \
+         ! appsink name=sink caps=video/x-raw,format=RGB,width={SIZE},height={SIZE}",
+        quote(path)
+    );
+    let pipeline = gst::parse::launch(&description)
+        .map_err(GStreamerError::Glib)?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| GStreamerError::BoolError(glib::bool_error!("Not a pipeline")))?;
+    let sink = pipeline
+        .by_name("sink")
+        .and_then(|sink| sink.downcast::<gstreamer_app::AppSink>().ok())
+        .ok_or_else(|| GStreamerError::BoolError(glib::bool_error!("Missing appsink")))?;
+
+    let result = pipeline
+        .set_state(gst::State::Playing)
+        .map_err(|err| GStreamerError::StateChangeError(err).into())
+        .and_then(|_| {
+            sink.try_pull_sample(gst::ClockTime::from_seconds(5))
+                .ok_or(Error::NoPicture)
+        });
+
+    if let Err(err) = pipeline.set_state(gst::State::Null) {
+        eprintln!("{err}");
+    }
+
+    let sample = result?;
+    let buffer = sample.buffer().ok_or(Error::NoPicture)?;
+    let info = sample
+        .caps()
+        .and_then(|caps| gst_video::VideoInfo::from_caps(caps).ok())
+        .ok_or(Error::NoPicture)?;
+    let map = buffer.map_readable().map_err(GStreamerError::BoolError)?;
+
+    // Rows may be padded past the width of the picture.
+    let stride = info.stride()[0] as usize;
+    let row = info.width() as usize * 3;
+
+    Ok(map
+        .chunks(stride)
+        .take(info.height() as usize)
+        .flat_map(|line| line[..row.min(line.len())].chunks_exact(3))
+        .map(|pixel| {
+            [
+                pixel[0] as f32 / 255.0,
+                pixel[1] as f32 / 255.0,
+                pixel[2] as f32 / 255.0,
+            ]
+        })
+        .collect())
+}
+
+/// Groups `pixels` into [`CLUSTERS`] colours, each with its number of pixels.
+fn cluster(pixels: &[[f32; 3]]) -> Vec<([f32; 3], usize)> {
+    if pixels.is_empty() {
+        return vec![];
+    }
+
+    // Starting from evenly spaced pixels keeps the result the same between
+    // runs.
+    let mut centres = (0..CLUSTERS)
+        .map(|index| pixels[index * pixels.len() / CLUSTERS])
+        .collect::<Vec<_>>();
+    let mut counts = vec![0; CLUSTERS];
+
+    for _ in 0..ITERATIONS {
+        let mut sums = vec![[0.0f32; 3]; CLUSTERS];
+        counts.fill(0);
+
+        for pixel in pixels {
+            let nearest = nearest(&centres, pixel);
+            counts[nearest] += 1;
+            for (sum, value) in sums[nearest].iter_mut().zip(pixel) {
+                *sum += value;
+            }
+        }
+
+        for (index, centre) in centres.iter_mut().enumerate() {
+            if counts[index] > 0 {
+                *centre = sums[index].map(|sum| sum / counts[index] as f32);
+            }
+        }
+    }
+
+    centres.into_iter().zip(counts).collect()
+}
+
+fn nearest(centres: &[[f32; 3]], pixel: &[f32; 3]) -> usize {
+    let distance = |centre: &[f32; 3]| {
+        centre
+            .iter()
+            .zip(pixel)
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f32>()
+    };
+
+    centres
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .map(|(index, _)| index)
+        .unwrap_or_default()
+}
+
+/// How colourful `color` is, by its chroma: bright, saturated colours score
+/// highest while greys score nothing.
+fn vibrance([r, g, b]: [f32; 3]) -> f32 {
+    r.max(g).max(b) - r.min(g).min(b)
+}
+
+fn rgb8(color: [f32; 3]) -> [u8; 3] {
+    color.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
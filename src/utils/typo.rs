@@ -1,10 +1,53 @@
 #![allow(dead_code)]
-pub const H1: f32 = 32.44;
-pub const H2: f32 = 28.83;
-pub const H3: f32 = 25.63;
-pub const H4: f32 = 22.78;
-pub const H5: f32 = 20.25;
-pub const H6: f32 = 18.00;
-pub const P: f32 = 16.0;
-pub const H7: f32 = 14.22;
-pub const H8: f32 = 12.64;
+//! Text sizes, on a scale of 1.125 from the body size.
+//!
+//! Every size grows with the text scale picked in the settings, so they are
+//! read when building the view rather than kept.
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static SCALE: AtomicU32 = AtomicU32::new(1.0f32.to_bits());
+
+/// Sets how much larger than the default all text is drawn.
+pub fn set_scale(scale: f32) {
+    SCALE.store(scale.to_bits(), Ordering::Relaxed);
+}
+
+pub fn scale() -> f32 {
+    f32::from_bits(SCALE.load(Ordering::Relaxed))
+}
+
+pub fn h1() -> f32 {
+    32.44 * scale()
+}
+
+pub fn h2() -> f32 {
+    28.83 * scale()
+}
+
+pub fn h3() -> f32 {
+    25.63 * scale()
+}
+
+pub fn h4() -> f32 {
+    22.78 * scale()
+}
+
+pub fn h5() -> f32 {
+    20.25 * scale()
+}
+
+pub fn h6() -> f32 {
+    18.00 * scale()
+}
+
+pub fn p() -> f32 {
+    16.0 * scale()
+}
+
+pub fn h7() -> f32 {
+    14.22 * scale()
+}
+
+pub fn h8() -> f32 {
+    12.64 * scale()
+}
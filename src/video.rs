@@ -1,10 +1,110 @@
+use crate::error::*;
+use crate::t;
 use crate::utils;
+use iced::Color;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use url::Url;
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub struct VideoId(usize);
 
 impl Eq for VideoId {}
 
+/// Where an episode sits within its show.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub season: u16,
+    pub episode: u16,
+}
+
+impl Series {
+    /// Reads the show, season and episode from a file name such as
+    /// `Show.Name.S01E02.Title`.
+    pub fn parse(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+
+        (0..bytes.len()).find_map(|start| {
+            let word = start > 0 && !bytes[start - 1].is_ascii_alphanumeric();
+            if !word || !bytes[start].eq_ignore_ascii_case(&b's') {
+                return None;
+            }
+
+            let rest = &name[start + 1..];
+            let season_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+            let rest = &rest[season_len..];
+            let rest = rest.strip_prefix(['e', 'E'])?;
+            let episode_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+            let show = name[..start]
+                .trim_end_matches(['.', '_', '-', ' '])
+                .replace(['.', '_'], " ");
+
+            if show.is_empty() || season_len == 0 || episode_len == 0 {
+                return None;
+            }
+
+            Some(Self {
+                name: show,
+                season: name[start + 1..start + 1 + season_len].parse().ok()?,
+                episode: rest[..episode_len].parse().ok()?,
+            })
+        })
+    }
+}
+
+/// A named position within a video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    /// The position in seconds.
+    pub position: f64,
+}
+
+/// The EBU R128 loudness of the audio of a video.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Loudness {
+    /// The integrated loudness, in LUFS.
+    pub integrated: f64,
+    /// The highest true peak of any channel, in dBTP.
+    pub true_peak: f64,
+}
+
+impl Loudness {
+    /// The loudness every video is brought to, that of ReplayGain 2.0.
+    pub const TARGET: f64 = -18.0;
+    /// The highest the true peak may be raised to.
+    pub const PEAK_CEILING: f64 = -1.0;
+
+    /// The gain in decibels bringing the video to [`Loudness::TARGET`],
+    /// limited so that its peaks do not clip.
+    pub fn gain(&self) -> f64 {
+        (Self::TARGET - self.integrated).min(Self::PEAK_CEILING - self.true_peak)
+    }
+}
+
+/// Colours picked from the poster or backdrop of a video.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Palette {
+    /// The colour covering most of the picture, as RGB.
+    pub dominant: [u8; 3],
+    /// The most colourful of the prominent colours, as RGB.
+    pub vibrant: [u8; 3],
+}
+
+impl Palette {
+    pub fn dominant(&self) -> Color {
+        let [r, g, b] = self.dominant;
+        Color::from_rgb8(r, g, b)
+    }
+
+    pub fn vibrant(&self) -> Color {
+        let [r, g, b] = self.vibrant;
+        Color::from_rgb8(r, g, b)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Video {
     pub id: VideoId,
@@ -21,9 +121,90 @@ pub struct Video {
     pub synapsis: String,
     pub tags: Vec<String>,
     pub backdrop: Option<String>,
+    /// For a stream, the text of [`Video::url`], which its settings are
+    /// stored under.
+    pub path: PathBuf,
+    /// Where a video which is not a local file is streamed from.
+    pub url: Option<Url>,
+    pub series: Option<Series>,
+    /// Where the end credits start, in milliseconds.
+    pub credits: Option<u64>,
+    /// Sorted by their position.
+    pub bookmarks: Vec<Bookmark>,
+    /// Unknown until the audio has been analysed.
+    pub loudness: Option<Loudness>,
+    /// Unknown until the artwork has been looked at.
+    pub palette: Option<Palette>,
 }
 
 impl Video {
+    /// A bare entry for a file which is not part of the library.
+    pub fn from_path(id: usize, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_owned();
+
+        Self {
+            id: VideoId(id),
+            series: Series::parse(&name),
+            name,
+            duration: 0,
+            rating: 0,
+            progress: 0.0,
+            poster: None,
+            release: 0,
+            added: 0,
+            recent: 0,
+            comments: 0,
+            watch_count: 0,
+            synapsis: String::default(),
+            tags: vec![],
+            backdrop: None,
+            path,
+            credits: None,
+            bookmarks: vec![],
+            loudness: None,
+            palette: None,
+            url: None,
+        }
+    }
+
+    /// A bare entry for a stream, such as one given on the command line.
+    pub fn from_url(id: usize, url: Url) -> Self {
+        let name = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .or(url.host_str())
+            .unwrap_or_default()
+            .to_owned();
+
+        Self {
+            name,
+            path: url.as_str().into(),
+            url: Some(url),
+            ..Self::from_path(id, PathBuf::new())
+        }
+    }
+
+    /// Whether the video is streamed rather than read from a file.
+    pub fn is_stream(&self) -> bool {
+        self.url.is_some()
+    }
+
+    /// The URL the video is played from.
+    pub fn uri(&self) -> Result<Url> {
+        if let Some(url) = self.url.as_ref() {
+            return Ok(url.clone());
+        }
+
+        let path = self.path.canonicalize()?;
+        Url::from_file_path(&path).map_err(|_| Error::InvalidPath(path))
+    }
+
     pub fn testing(id: usize) -> Self {
         let duration = (id * utils::rand_u32() as usize) as u64;
 
@@ -42,7 +223,13 @@ impl Video {
             synapsis: "In 1926, Newt Scamander arrives at the Magical Congress of the United States of America with a magically expanded briefcase, which houses a number of dangerous creatures and their habitats. When the creatures escape from the briefcase, it sends the American wizarding authorities after Newt, and threatens to strain even further the state of magical and non-magical relations.".to_owned(),
             tags: vec!["tag-1".into(), "tag-2".into(), "tag-team".into()],
             backdrop: Some("assets/player1.jpg".into()),
-
+            path: "assets/test.mkv".into(),
+            series: None,
+            credits: None,
+            bookmarks: vec![],
+            loudness: None,
+            palette: None,
+            url: None,
         }
     }
 
@@ -63,8 +250,14 @@ impl Video {
             watch_count: 1,
             synapsis: "When the creator of a popular video game system dies, a virtual contest is created to compete for his fortune.".to_owned(),
             tags: vec!["Adventure", "Action", "Science Fiction"].into_iter().map(ToOwned::to_owned).collect(),
-            backdrop: Some("assets/test.jpg".into())
-
+            backdrop: Some("assets/test.jpg".into()),
+            path: "assets/test.mkv".into(),
+            series: None,
+            credits: None,
+            bookmarks: vec![],
+            loudness: None,
+            palette: None,
+            url: None,
         }
     }
 
@@ -92,14 +285,14 @@ impl Video {
     pub fn duration_full(&self) -> String {
         let hrs = self.duration / 3600;
         let hrs = if hrs > 0 {
-            format!("{hrs} hour{}", if hrs > 1 { "s" } else { "" })
+            t!("duration-hours", hours = hrs)
         } else {
             String::default()
         };
 
         let mins = (self.duration % 3600) / 60;
         let mins = if mins > 0 {
-            format!("{mins} min{}", if mins > 1 { "s" } else { "" })
+            t!("duration-minutes", minutes = mins)
         } else {
             String::default()
         };
@@ -19,6 +19,14 @@ use iced::{
 
 use std::{ops::RangeInclusive, time::Duration};
 
+/// A point of interest drawn on the rail of a [`VideoSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker {
+    /// The position of the marker, in the units of the slider's range.
+    pub value: f64,
+    pub color: Color,
+}
+
 /// A customised version of the [`iced::widget::Slider`] for video playback.
 /// This version includes detection for hovering on the rail. The cursor type
 /// for interactions is changed to an [`iced::mouse::Interaction::Pointer`].
@@ -34,6 +42,10 @@ where
     thumbnails: Vec<Renderer::Handle>,
     thumbnail_font: <Renderer as text::Renderer>::Font,
     duration: Duration,
+    /// The start and title of each chapter, in the units of `range`.
+    chapters: Vec<(f64, String)>,
+    markers: Vec<Marker>,
+    highlights: Vec<(RangeInclusive<f64>, Color)>,
     default: Option<f64>,
     on_change: Box<dyn Fn(f64) -> Message + 'a>,
     on_release: Option<Message>,
@@ -90,6 +102,9 @@ where
             step: 1.0,
             thumbnails,
             duration,
+            chapters: vec![],
+            markers: vec![],
+            highlights: vec![],
             thumbnail_font,
             shift_step: None,
             on_change: Box::new(on_change),
@@ -120,6 +135,28 @@ where
         self
     }
 
+    /// Sets the chapters of the [`Slider`], given as their start and title.
+    ///
+    /// Chapter boundaries are marked on the rail and the title of the hovered
+    /// chapter is shown next to the timestamp.
+    pub fn chapters(mut self, chapters: impl IntoIterator<Item = (f64, String)>) -> Self {
+        self.chapters = chapters.into_iter().collect();
+        self
+    }
+
+    /// Sets the markers drawn on the rail of the [`Slider`].
+    pub fn markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
+    /// Highlights a section of the rail of the [`Slider`] with `color`.
+    /// Later highlights are drawn over earlier ones.
+    pub fn highlight(mut self, range: Option<RangeInclusive<f64>>, color: Color) -> Self {
+        self.highlights.extend(range.map(|range| (range, color)));
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -420,6 +457,76 @@ where
             style.rail.backgrounds.1,
         );
 
+        // The horizontal position of `value` on the rail, relative to its bounds.
+        let locate = |value: f64| {
+            (bounds.width - handle_width) * (value as f32 - range_start) / (range_end - range_start)
+                + handle_width / 2.0
+        };
+
+        for (range, color) in self.highlights.iter().filter(|_| range_start < range_end) {
+            let start = locate(range.start().clamp(range_start as f64, range_end as f64));
+            let end = locate(range.end().clamp(range_start as f64, range_end as f64));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + start,
+                        y: rail_y - style.rail.width / 2.0 - 1.0,
+                        width: (end - start).max(0.0),
+                        height: style.rail.width + 2.0,
+                    },
+                    border: style.rail.border,
+                    ..renderer::Quad::default()
+                },
+                Background::Color(*color),
+            );
+        }
+
+        if range_start < range_end {
+            for (start, _) in &self.chapters {
+                let start = *start as f32;
+
+                if start <= range_start || start >= range_end {
+                    continue;
+                }
+
+                let x = locate(start as f64);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x - 1.0,
+                            y: rail_y - style.rail.width / 2.0 - 2.0,
+                            width: 2.0,
+                            height: style.rail.width + 4.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(Color::BLACK.scale_alpha(0.7)),
+                );
+            }
+        }
+
+        if range_start < range_end {
+            for marker in &self.markers {
+                let x = locate(marker.value.clamp(range_start as f64, range_end as f64));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x - 1.5,
+                            y: rail_y - handle_height / 2.0,
+                            width: 3.0,
+                            height: handle_height,
+                        },
+                        border: border::rounded(1.5),
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(marker.color),
+                );
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -490,6 +597,18 @@ where
             position % 60
         );
 
+        let chapter = self
+            .chapters
+            .iter()
+            .take_while(|(start, _)| *start <= state.cursor_location)
+            .last();
+
+        let timestamp = match chapter {
+            Some((_, title)) => format!("{title} · {timestamp}"),
+            None => timestamp,
+        };
+        let width = (timestamp.chars().count() as f32 * 7.5 + 16.0).max(80.0);
+
         let mut overlay = vec![];
 
         if let Some(image) = self.thumbnails.get(image_index).cloned() {
@@ -512,7 +631,7 @@ where
                 position: layout.position() + translation,
                 content_bounds: layout.bounds(),
                 timestamp,
-                size: iced::Size::new(80.0, 20.0),
+                size: iced::Size::new(width, 20.0),
                 cursor_position: state.cursor_position,
                 font: self.thumbnail_font,
             },